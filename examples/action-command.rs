@@ -0,0 +1,58 @@
+use pylon_cxx::HasProperties;
+
+const COUNT_IMAGES_TO_GRAB: u32 = 10;
+
+fn main() -> anyhow::Result<()> {
+    // Before using any pylon methods, the pylon runtime must be initialized.
+    let pylon = pylon_cxx::Pylon::new();
+
+    let tl_factory = pylon_cxx::TlFactory::instance(&pylon);
+
+    // All cameras share the same keys, so a single action command triggers
+    // all of them at once.
+    let command = pylon_cxx::ActionCommand::new(0x4711, 0x1);
+
+    let mut cameras = vec![];
+    for device in tl_factory.enumerate_devices()? {
+        if device.property_value("DeviceClass")? != "BaslerGigE" {
+            continue;
+        }
+        let camera = tl_factory.create_device(&device)?;
+        println!("Using device {}", camera.device_info().model_name()?);
+        camera.open()?;
+        camera.configure_action_trigger(&command)?;
+        camera.start_grabbing(&pylon_cxx::GrabOptions::default().count(COUNT_IMAGES_TO_GRAB))?;
+        cameras.push(camera);
+    }
+
+    let gige_tl = tl_factory.create_gige_transport_layer()?;
+    let mut grab_result = pylon_cxx::GrabResult::new()?;
+
+    for _ in 0..COUNT_IMAGES_TO_GRAB {
+        gige_tl.issue_action_command(&command, "255.255.255.255")?;
+
+        for camera in cameras.iter() {
+            camera.retrieve_result(
+                5000,
+                &mut grab_result,
+                pylon_cxx::TimeoutHandling::ThrowException,
+            )?;
+            if grab_result.grab_succeeded()? {
+                println!(
+                    "{}: block id {}, time stamp {}",
+                    camera.device_info().model_name()?,
+                    grab_result.block_id()?,
+                    grab_result.time_stamp()?
+                );
+            } else {
+                println!(
+                    "Error: {} {}",
+                    grab_result.error_code()?,
+                    grab_result.error_description()?
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -36,6 +36,7 @@ async fn main() -> anyhow::Result<()> {
     // blocked here, but while waiting other async parts of your application may do useful
     // work. That's the purpose of async-await.
     while let Some(grab_result) = camera.next().await {
+        let grab_result = grab_result?;
         // Image grabbed successfully?
         if grab_result.grab_succeeded()? {
             // Access the image data.
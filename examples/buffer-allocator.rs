@@ -0,0 +1,69 @@
+use std::alloc::{alloc, dealloc, Layout};
+use std::io;
+use std::ptr::NonNull;
+
+const COUNT_IMAGES_TO_GRAB: u32 = 10;
+const PAGE_SIZE: usize = 4096;
+
+/// Allocates page-aligned buffers.
+struct PageAligned;
+
+unsafe impl pylon_cxx::BufferAllocator for PageAligned {
+    fn allocate(&mut self, size: usize) -> pylon_cxx::PylonResult<(NonNull<u8>, isize)> {
+        // Allocating zero bytes is undefined behavior. Errors are returned to
+        // pylon, a panic here would abort the process.
+        if size == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "empty buffer").into());
+        }
+        let layout = Layout::from_size_align(size, PAGE_SIZE)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        // The buffer context remembers the size, which is needed to free it.
+        let buffer = NonNull::new(unsafe { alloc(layout) })
+            .ok_or_else(|| io::Error::from(io::ErrorKind::OutOfMemory))?;
+        Ok((buffer, size as isize))
+    }
+
+    unsafe fn free(&mut self, buffer: NonNull<u8>, context: isize) {
+        // The layout was valid when the buffer was allocated.
+        if let Ok(layout) = Layout::from_size_align(context as usize, PAGE_SIZE) {
+            dealloc(buffer.as_ptr(), layout);
+        }
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    // Before using any pylon methods, the pylon runtime must be initialized.
+    let pylon = pylon_cxx::Pylon::new();
+
+    // Create an instant camera object with the camera device found first.
+    let camera = pylon_cxx::TlFactory::instance(&pylon).create_first_device()?;
+
+    // Print the model name of the camera.
+    println!("Using device {:?}", camera.device_info().model_name()?);
+
+    camera.open()?;
+
+    // The buffers are allocated when grabbing starts.
+    camera.set_buffer_allocator(PageAligned)?;
+    camera.start_grabbing(&pylon_cxx::GrabOptions::default().count(COUNT_IMAGES_TO_GRAB))?;
+
+    let mut grab_result = pylon_cxx::GrabResult::new()?;
+    while camera.is_grabbing() {
+        camera.retrieve_result(
+            5000,
+            &mut grab_result,
+            pylon_cxx::TimeoutHandling::ThrowException,
+        )?;
+        if grab_result.grab_succeeded()? {
+            let buffer = grab_result.buffer()?;
+            println!(
+                "Buffer at {:p} of {} bytes, page aligned: {}",
+                buffer.as_ptr(),
+                grab_result.buffer_context()?,
+                buffer.as_ptr().align_offset(PAGE_SIZE) == 0
+            );
+        }
+    }
+
+    Ok(())
+}
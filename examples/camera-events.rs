@@ -0,0 +1,41 @@
+const COUNT_IMAGES_TO_GRAB: u32 = 5;
+
+fn main() -> anyhow::Result<()> {
+    // Before using any pylon methods, the pylon runtime must be initialized.
+    let pylon = pylon_cxx::Pylon::new();
+
+    // Create an instant camera object with the camera device found first.
+    let camera = pylon_cxx::TlFactory::instance(&pylon).create_first_device()?;
+
+    // Print the model name of the camera.
+    println!("Using device {:?}", camera.device_info().model_name()?);
+
+    camera.open()?;
+
+    // Have the camera send an event at the end of each exposure and subscribe
+    // to its `EventExposureEndData` node (`ExposureEndEventData` on older
+    // cameras), which pylon updates when the event arrives. Dropping the
+    // registration unsubscribes.
+    camera.enable_camera_event("ExposureEnd")?;
+    let _registration = camera.on_camera_event("ExposureEnd", |_camera, event| {
+        println!(
+            "{}: frame ID {:?}, timestamp {:?}",
+            event.name, event.frame_id, event.timestamp
+        );
+    })?;
+
+    camera.start_grabbing(&pylon_cxx::GrabOptions::default().count(COUNT_IMAGES_TO_GRAB))?;
+
+    let mut grab_result = pylon_cxx::GrabResult::new()?;
+    while camera.is_grabbing() {
+        camera.retrieve_result(
+            5000,
+            &mut grab_result,
+            pylon_cxx::TimeoutHandling::ThrowException,
+        )?;
+    }
+
+    camera.disable_camera_event("ExposureEnd")?;
+
+    Ok(())
+}
@@ -27,17 +27,11 @@ fn main() -> anyhow::Result<()> {
         Err(e) => eprintln!("Ignoring error getting PixelFormat node: {}", e),
     };
 
-    let mut grab_result = pylon_cxx::GrabResult::new()?;
-
     // Camera.StopGrabbing() is called automatically by the RetrieveResult() method
-    // when c_countOfImagesToGrab images have been retrieved.
-    while camera.is_grabbing() {
-        // Wait for an image and then retrieve it. A timeout of 5000 ms is used.
-        camera.retrieve_result(
-            5000,
-            &mut grab_result,
-            pylon_cxx::TimeoutHandling::ThrowException,
-        )?;
+    // when c_countOfImagesToGrab images have been retrieved, which ends the loop.
+    // A timeout of 5000 ms is used.
+    for grab_result in camera.frames(5000) {
+        let grab_result = grab_result?;
 
         // Image grabbed successfully?
         if grab_result.grab_succeeded()? {
@@ -0,0 +1,46 @@
+use std::sync::mpsc;
+
+const COUNT_IMAGES_TO_GRAB: u32 = 10;
+
+fn main() -> anyhow::Result<()> {
+    // Before using any pylon methods, the pylon runtime must be initialized.
+    let pylon = pylon_cxx::Pylon::new();
+
+    // Create an instant camera object with the camera device found first.
+    let camera = pylon_cxx::TlFactory::instance(&pylon).create_first_device()?;
+
+    // Print the model name of the camera.
+    println!("Using device {:?}", camera.device_info().model_name()?);
+
+    camera.open()?;
+
+    // The handler is called from pylon's grab loop thread.
+    let (tx, rx) = mpsc::channel();
+    let _registration = camera.register_image_event_handler(
+        pylon_cxx::RegistrationMode::ReplaceAll,
+        move |camera, grab_result| {
+            if let Ok(true) = grab_result.grab_succeeded() {
+                println!(
+                    "{:?}: SizeX: {} SizeY: {}",
+                    camera.device_info().model_name(),
+                    grab_result.width().unwrap_or(0),
+                    grab_result.height().unwrap_or(0),
+                );
+            }
+            let _ = tx.send(());
+        },
+    )?;
+
+    camera.start_grabbing(
+        &pylon_cxx::GrabOptions::default()
+            .count(COUNT_IMAGES_TO_GRAB)
+            .grab_loop(pylon_cxx::GrabLoop::ProvidedByInstantCamera),
+    )?;
+
+    for _ in 0..COUNT_IMAGES_TO_GRAB {
+        rx.recv()?;
+    }
+    camera.stop_grabbing()?;
+
+    Ok(())
+}
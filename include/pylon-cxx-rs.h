@@ -13,19 +13,179 @@ namespace Pylon
         Return,
         ThrowException,
     };
+    // These are scoped enums because pylon already defines `Control`,
+    // `Stream`, etc. in the Pylon namespace.
+    enum class AccessMode : uint32_t
+    {
+        Control,
+        Stream,
+        Event,
+        Exclusive,
+        Monitor,
+    };
+    enum class DeviceAccessibility : uint32_t
+    {
+        Unknown,
+        Ok,
+        Opened,
+        OpenedExclusively,
+        NotReachable,
+    };
+
+    // Owns a GigE transport layer and releases it on destruction.
+    class GigETransportLayerHandle
+    {
+    public:
+        GigETransportLayerHandle(IGigETransportLayer *tl);
+        ~GigETransportLayerHandle();
+        IGigETransportLayer *tl;
+    };
+
+    // Defined in Rust.
+    struct ConfigurationEventHandlerBox;
+
+    // Forwards configuration events to a Rust handler.
+    class RustConfigurationEventHandler : public CConfigurationEventHandler
+    {
+    public:
+        RustConfigurationEventHandler(rust::Box<ConfigurationEventHandlerBox> handler);
+        ~RustConfigurationEventHandler();
+        void OnAttach(CInstantCamera &camera) override;
+        void OnAttached(CInstantCamera &camera) override;
+        void OnDetach(CInstantCamera &camera) override;
+        void OnDetached(CInstantCamera &camera) override;
+        void OnDestroy(CInstantCamera &camera) override;
+        void OnDestroyed(CInstantCamera &camera) override;
+        void OnOpen(CInstantCamera &camera) override;
+        void OnOpened(CInstantCamera &camera) override;
+        void OnClose(CInstantCamera &camera) override;
+        void OnClosed(CInstantCamera &camera) override;
+        void OnGrabStart(CInstantCamera &camera) override;
+        void OnGrabStarted(CInstantCamera &camera) override;
+        void OnGrabStop(CInstantCamera &camera) override;
+        void OnGrabStopped(CInstantCamera &camera) override;
+        void OnGrabError(CInstantCamera &camera, const char *message) override;
+        void OnCameraDeviceRemoved(CInstantCamera &camera) override;
+
+    private:
+        rust::Box<ConfigurationEventHandlerBox> handler;
+    };
+
     enum GrabStrategy {
         OneByOne,
         LatestImageOnly,
         LatestImages,
         UpcomingImage
     };
+    enum class BuiltinConfiguration : uint32_t
+    {
+        SoftwareTrigger,
+        AcquireSingleFrame,
+        AcquireContinuous,
+    };
+    enum class ConfigurationEvent : uint32_t
+    {
+        Attach,
+        Attached,
+        Detach,
+        Detached,
+        Destroy,
+        Destroyed,
+        Open,
+        Opened,
+        Close,
+        Closed,
+        GrabStart,
+        GrabStarted,
+        GrabStop,
+        GrabStopped,
+        CameraDeviceRemoved,
+    };
+    enum class GrabLoop : uint32_t
+    {
+        ProvidedByInstantCamera,
+        ProvidedByUser,
+    };
+    enum class RegistrationMode : uint32_t
+    {
+        Append,
+        ReplaceAll,
+    };
+
+    // Defined in Rust.
+    struct ConfigurationBox;
+
+    // Runs a Rust configuration when the camera has been opened.
+    class RustConfiguration : public CConfigurationEventHandler
+    {
+    public:
+        RustConfiguration(rust::Box<ConfigurationBox> configuration);
+        ~RustConfiguration();
+        void OnOpened(CInstantCamera &camera) override;
+
+    private:
+        rust::Box<ConfigurationBox> configuration;
+    };
+
+    // Defined in Rust.
+    struct BufferAllocatorBox;
+
+    // Allocates grab buffers with a Rust allocator.
+    class RustBufferFactory : public IBufferFactory
+    {
+    public:
+        RustBufferFactory(rust::Box<BufferAllocatorBox> allocator);
+        ~RustBufferFactory();
+        void AllocateBuffer(size_t buffer_size, void **created_buffer, intptr_t &buffer_context) override;
+        void FreeBuffer(void *created_buffer, intptr_t buffer_context) override;
+        void DestroyBufferFactory() override;
+
+    private:
+        rust::Box<BufferAllocatorBox> allocator;
+    };
+
+    // Defined in Rust.
+    struct ImageEventHandlerBox;
+
+    // Forwards grabbed images to a Rust handler.
+    class RustImageEventHandler : public CImageEventHandler
+    {
+    public:
+        RustImageEventHandler(rust::Box<ImageEventHandlerBox> handler);
+        ~RustImageEventHandler();
+        void OnImageGrabbed(CInstantCamera &camera, const CGrabResultPtr &grab_result) override;
+
+    private:
+        rust::Box<ImageEventHandlerBox> handler;
+    };
+
+    // Defined in Rust.
+    struct CameraEventHandlerBox;
+
+    // Forwards camera events to a Rust handler.
+    class RustCameraEventHandler : public CCameraEventHandler
+    {
+    public:
+        RustCameraEventHandler(rust::Box<CameraEventHandlerBox> handler);
+        ~RustCameraEventHandler();
+        void OnCameraEvent(CInstantCamera &camera, intptr_t user_provided_id, GenApi::INode *node) override;
+
+    private:
+        rust::Box<CameraEventHandlerBox> handler;
+    };
 
     std::unique_ptr<CInstantCamera> tl_factory_create_first_device();
     std::unique_ptr<CInstantCamera> tl_factory_create_device(const CDeviceInfo &);
     std::unique_ptr<std::vector<CDeviceInfo>> tl_factory_enumerate_devices();
+    DeviceAccessibility tl_factory_is_device_accessible(const CDeviceInfo &, uint32_t);
+    std::unique_ptr<GigETransportLayerHandle> tl_factory_create_gige_transport_layer();
+
+    void gige_transport_layer_issue_action_command(const std::unique_ptr<GigETransportLayerHandle> &, uint32_t, uint32_t, uint32_t, rust::Str);
+    void gige_transport_layer_issue_scheduled_action_command(const std::unique_ptr<GigETransportLayerHandle> &, uint32_t, uint32_t, uint32_t, uint64_t, rust::Str);
 
     std::unique_ptr<CDeviceInfo> instant_camera_get_device_info(const std::unique_ptr<CInstantCamera> &);
     void instant_camera_open(const std::unique_ptr<CInstantCamera> &);
+    void instant_camera_open_with_access_mode(const std::unique_ptr<CInstantCamera> &, uint32_t);
     bool instant_camera_is_open(const std::unique_ptr<CInstantCamera> &);
     void instant_camera_close(const std::unique_ptr<CInstantCamera> &);
 
@@ -46,7 +206,34 @@ namespace Pylon
     void instant_camera_start_grabbing_with_count(const std::unique_ptr<CInstantCamera> &, uint32_t);
     void instant_camera_start_grabbing_with_count_and_strategy(const std::unique_ptr<CInstantCamera> &, uint32_t, GrabStrategy);
     void instant_camera_stop_grabbing(const std::unique_ptr<CInstantCamera> &);
+    void instant_camera_start_grabbing_with_grab_loop(const std::unique_ptr<CInstantCamera> &, GrabStrategy, GrabLoop);
+    void instant_camera_start_grabbing_with_count_and_grab_loop(const std::unique_ptr<CInstantCamera> &, uint32_t, GrabStrategy, GrabLoop);
     bool instant_camera_is_grabbing(const std::unique_ptr<CInstantCamera> &);
+    bool instant_camera_is_camera_device_removed(const std::unique_ptr<CInstantCamera> &);
+    void instant_camera_destroy_device(const std::unique_ptr<CInstantCamera> &);
+    void instant_camera_attach_device(const std::unique_ptr<CInstantCamera> &, const CDeviceInfo &);
+
+    std::unique_ptr<RustConfigurationEventHandler> new_configuration_event_handler(rust::Box<ConfigurationEventHandlerBox>);
+    void instant_camera_register_configuration_event_handler(const std::unique_ptr<CInstantCamera> &, const std::unique_ptr<RustConfigurationEventHandler> &, RegistrationMode);
+    bool instant_camera_deregister_configuration_event_handler(const std::unique_ptr<CInstantCamera> &, const std::unique_ptr<RustConfigurationEventHandler> &);
+
+    void instant_camera_register_builtin_configuration(const std::unique_ptr<CInstantCamera> &, BuiltinConfiguration, RegistrationMode);
+    void instant_camera_register_configuration(const std::unique_ptr<CInstantCamera> &, rust::Box<ConfigurationBox>, RegistrationMode);
+
+    void instant_camera_set_buffer_factory(const std::unique_ptr<CInstantCamera> &, rust::Box<BufferAllocatorBox>);
+
+    std::unique_ptr<RustImageEventHandler> new_image_event_handler(rust::Box<ImageEventHandlerBox>);
+    void instant_camera_register_image_event_handler(const std::unique_ptr<CInstantCamera> &, const std::unique_ptr<RustImageEventHandler> &, RegistrationMode);
+    bool instant_camera_deregister_image_event_handler(const std::unique_ptr<CInstantCamera> &, const std::unique_ptr<RustImageEventHandler> &);
+
+    std::unique_ptr<RustCameraEventHandler> new_camera_event_handler(rust::Box<CameraEventHandlerBox>);
+    void instant_camera_register_camera_event_handler(const std::unique_ptr<CInstantCamera> &, const std::unique_ptr<RustCameraEventHandler> &, rust::Str);
+    bool instant_camera_deregister_camera_event_handler(const std::unique_ptr<CInstantCamera> &, const std::unique_ptr<RustCameraEventHandler> &, rust::Str);
+
+    std::unique_ptr<CDeviceInfo> instant_camera_ref_get_device_info(const CInstantCamera &);
+    const MyNodeMap& instant_camera_ref_get_node_map(const CInstantCamera &);
+    bool instant_camera_ref_is_grabbing(const CInstantCamera &);
+    bool instant_camera_ref_is_camera_device_removed(const CInstantCamera &);
 
     #if defined(FEATURE_STREAM_UNIX)
     int instant_camera_wait_object_fd(const std::unique_ptr<CInstantCamera> &);
@@ -56,12 +243,18 @@ namespace Pylon
     #endif
 
     bool instant_camera_retrieve_result(const std::unique_ptr<CInstantCamera> &, uint32_t, std::unique_ptr<CGrabResultPtr> &, TimeoutHandling);
+    void instant_camera_grab_one(const std::unique_ptr<CInstantCamera> &, uint32_t, std::unique_ptr<CGrabResultPtr> &);
+    bool instant_camera_can_wait_for_frame_trigger_ready(const std::unique_ptr<CInstantCamera> &);
+    bool instant_camera_wait_for_frame_trigger_ready(const std::unique_ptr<CInstantCamera> &, uint32_t, TimeoutHandling);
+    void instant_camera_execute_software_trigger(const std::unique_ptr<CInstantCamera> &);
 
+    bool node_map_has_node(const MyNodeMap&, rust::Str);
     std::unique_ptr<CBooleanParameter> node_map_get_boolean_parameter(const MyNodeMap&, rust::Str);
     std::unique_ptr<CIntegerParameter> node_map_get_integer_parameter(const MyNodeMap&, rust::Str);
     std::unique_ptr<CFloatParameter> node_map_get_float_parameter(const MyNodeMap&, rust::Str);
     std::unique_ptr<CEnumParameter> node_map_get_enum_parameter(const MyNodeMap&, rust::Str);
     std::unique_ptr<CCommandParameter> node_map_get_command_parameter(const MyNodeMap&, rust::Str);
+    std::unique_ptr<CStringParameter> node_map_get_string_parameter(const MyNodeMap&, rust::Str);
 
     bool boolean_node_get_value(const std::unique_ptr<CBooleanParameter> &);
     void boolean_node_set_value(const std::unique_ptr<CBooleanParameter> &, bool);
@@ -84,7 +277,11 @@ namespace Pylon
 
     void command_node_execute(const std::unique_ptr<CCommandParameter> &, bool);
 
+    rust::String string_node_get_value(const std::unique_ptr<CStringParameter> &);
+    void string_node_set_value(const std::unique_ptr<CStringParameter> &, rust::Str);
+
     std::unique_ptr<CGrabResultPtr> new_grab_result_ptr();
+    std::unique_ptr<CGrabResultPtr> grab_result_clone(const std::unique_ptr<CGrabResultPtr> &);
     bool grab_result_grab_succeeded(const std::unique_ptr<CGrabResultPtr> &);
     rust::String grab_result_error_description(const std::unique_ptr<CGrabResultPtr> &);
     uint32_t grab_result_error_code(const std::unique_ptr<CGrabResultPtr> &);
@@ -99,15 +296,20 @@ namespace Pylon
     uint32_t grab_result_buffer_size(const std::unique_ptr<CGrabResultPtr> &);
 
     uint64_t grab_result_block_id(const std::unique_ptr<CGrabResultPtr> &grab_result);
+    intptr_t grab_result_buffer_context(const std::unique_ptr<CGrabResultPtr> &grab_result);
     uint64_t grab_result_time_stamp(const std::unique_ptr<CGrabResultPtr> &grab_result);
     size_t grab_result_stride(const std::unique_ptr<CGrabResultPtr> &grab_result);
     uint32_t grab_result_image_size(const std::unique_ptr<CGrabResultPtr> &grab_result);
+    size_t grab_result_number_of_skipped_images(const std::unique_ptr<CGrabResultPtr> &grab_result);
     const MyNodeMap& grab_result_get_chunk_data_node_map(const std::unique_ptr<CGrabResultPtr> &grab_result);
 
     std::unique_ptr<CDeviceInfo> device_info_copy(const CDeviceInfo &);
     std::unique_ptr<std::vector<std::string>> device_info_get_property_names(const std::unique_ptr<CDeviceInfo> &);
     rust::String device_info_get_property_value(const std::unique_ptr<CDeviceInfo> &, rust::Str);
     rust::String device_info_get_model_name(const std::unique_ptr<CDeviceInfo> &);
+    rust::String device_info_get_serial_number(const std::unique_ptr<CDeviceInfo> &);
+    rust::String device_info_get_full_name(const std::unique_ptr<CDeviceInfo> &);
+    rust::String device_info_get_device_class(const std::unique_ptr<CDeviceInfo> &);
 
     #if defined(FEATURE_STREAM_WINDOWS)
     bool wait_object_wait(const std::unique_ptr<WaitObject>& wait_object, uint64_t timeout);
@@ -0,0 +1,148 @@
+//! Async versions of camera operations which block.
+//!
+//! The operations run on tokio's blocking thread pool, so they do not stall
+//! the runtime. Outside of a tokio runtime, e.g. with the `async-io` feature
+//! on another executor, each operation runs on a thread of its own.
+//! Operations on a camera take the camera by value and return it when they are
+//! done, because a borrowed camera could be dropped while the operation is
+//! still running.
+
+use std::path::PathBuf;
+
+use tokio::runtime::Handle;
+use tokio::sync::oneshot;
+
+use crate::{DetachedCamera, DeviceInfo, InstantCamera, Pylon, PylonError, PylonResult, TlFactory};
+
+/// Run `f` on the blocking thread pool of the current runtime, or on a new
+/// thread if there is none.
+pub(crate) async fn spawn_blocking<F, R>(f: F) -> PylonResult<R>
+where
+    F: FnOnce() -> PylonResult<R> + Send + 'static,
+    R: Send + 'static,
+{
+    match Handle::try_current() {
+        Ok(handle) => handle
+            .spawn_blocking(f)
+            .await
+            .map_err(|e| PylonError::new(format!("Blocking task failed: {}", e)))?,
+        Err(_) => {
+            let (tx, rx) = oneshot::channel();
+            std::thread::Builder::new()
+                .name("pylon-blocking".into())
+                .spawn(move || {
+                    let _ = tx.send(f());
+                })
+                .map_err(|e| PylonError::new(format!("Could not spawn thread: {}", e)))?;
+            rx.await
+                .map_err(|_| PylonError::new("Blocking thread panicked"))?
+        }
+    }
+}
+
+/// A camera on the blocking thread pool.
+struct BlockingCamera {
+    camera: DetachedCamera,
+    // Dropped after `camera`, in case the camera is dropped before it reaches
+    // the caller.
+    _pylon: Pylon,
+}
+
+impl<'a> TlFactory<'a> {
+    /// Async version of [TlFactory::enumerate_devices].
+    pub async fn enumerate_devices_async(&self) -> PylonResult<Vec<DeviceInfo>> {
+        let pylon = self.lib.retain();
+        spawn_blocking(move || TlFactory::instance(&pylon).enumerate_devices()).await
+    }
+
+    /// Async version of [TlFactory::create_device].
+    pub async fn create_device_async(
+        &self,
+        device_info: &DeviceInfo,
+    ) -> PylonResult<InstantCamera<'a>> {
+        let device_info = device_info.clone();
+        let pylon = self.lib.retain();
+        let created = spawn_blocking(move || {
+            let camera = TlFactory::instance(&pylon).create_device(&device_info)?;
+            Ok(BlockingCamera {
+                camera: DetachedCamera::new(camera),
+                _pylon: pylon,
+            })
+        })
+        .await?;
+        Ok(created.camera.attach(self.lib))
+    }
+}
+
+impl InstantCamera<'_> {
+    /// Async version of [InstantCamera::open].
+    ///
+    /// The camera is returned once it is open. If opening fails, the camera
+    /// is dropped.
+    pub async fn open_async(self) -> PylonResult<Self> {
+        let (camera, ()) = self.run_blocking(|camera| camera.open()).await?;
+        Ok(camera)
+    }
+
+    /// Async version of [InstantCamera::close].
+    ///
+    /// The camera is returned once it is closed. If closing fails, the camera
+    /// is dropped.
+    pub async fn close_async(self) -> PylonResult<Self> {
+        let (camera, ()) = self.run_blocking(|camera| camera.close()).await?;
+        Ok(camera)
+    }
+
+    /// Async version of [NodeMap::load](crate::NodeMap::load) for the node map
+    /// of the open camera.
+    ///
+    /// The camera is returned once the node values are loaded. If loading
+    /// fails, the camera is dropped.
+    pub async fn load_node_map_async<P: Into<PathBuf>>(
+        self,
+        path: P,
+        validate: bool,
+    ) -> PylonResult<Self> {
+        let path = path.into();
+        let (camera, ()) = self
+            .run_blocking(move |camera| camera.node_map()?.load(path, validate))
+            .await?;
+        Ok(camera)
+    }
+
+    /// Async version of [CommandNode::execute](crate::CommandNode::execute)
+    /// for the command node `name` of the open camera.
+    ///
+    /// The camera is returned once the command is executed. If executing
+    /// fails, the camera is dropped.
+    pub async fn execute_command_async(self, name: &str, verify: bool) -> PylonResult<Self> {
+        let name = name.to_string();
+        let (camera, ()) = self
+            .run_blocking(move |camera| camera.node_map()?.command_node(&name)?.execute(verify))
+            .await?;
+        Ok(camera)
+    }
+
+    /// Move the camera to the blocking thread pool, run `f` with it and move
+    /// it back together with the result of `f`.
+    pub(crate) async fn run_blocking<F, R>(self, f: F) -> PylonResult<(Self, R)>
+    where
+        F: FnOnce(&InstantCamera) -> PylonResult<R> + Send + 'static,
+        R: Send + 'static,
+    {
+        let lib = self._lib;
+        let pylon = lib.retain();
+        let detached = DetachedCamera::new(self);
+        let (done, result) = spawn_blocking(move || {
+            let camera = detached.attach(&pylon);
+            let result = f(&camera)?;
+            let done = BlockingCamera {
+                camera: DetachedCamera::new(camera),
+                _pylon: pylon,
+            };
+            Ok((done, result))
+        })
+        .await?;
+        Ok((done.camera.attach(lib), result))
+    }
+}
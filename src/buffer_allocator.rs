@@ -0,0 +1,83 @@
+use std::ptr::NonNull;
+use std::sync::Mutex;
+
+use crate::{ffi, CxxResultExt, InstantCamera, PylonError, PylonResult};
+
+/// Allocates the buffers pylon grabs images into.
+///
+/// Set it with [InstantCamera::set_buffer_allocator]. pylon allocates all
+/// buffers when grabbing starts and frees them after grabbing has stopped and
+/// all grab results referring to them have been dropped. The allocator may be
+/// called from any thread.
+///
+/// # Safety
+///
+/// A buffer returned by [BufferAllocator::allocate] must be valid for reads
+/// and writes of `size` bytes and must not be accessed otherwise until it is
+/// passed to [BufferAllocator::free]. The methods must not panic; a panic
+/// aborts the process.
+pub unsafe trait BufferAllocator: Send {
+    /// Allocate a buffer of `size` bytes.
+    ///
+    /// Returns the buffer and a context value, which is passed to
+    /// [BufferAllocator::free] and is available from
+    /// [GrabResult::buffer_context](crate::GrabResult::buffer_context).
+    fn allocate(&mut self, size: usize) -> PylonResult<(NonNull<u8>, isize)>;
+
+    /// Free a buffer returned by [BufferAllocator::allocate].
+    ///
+    /// # Safety
+    ///
+    /// `buffer` and `context` must have been returned by
+    /// [BufferAllocator::allocate] of this allocator and the buffer must not
+    /// be used anymore.
+    unsafe fn free(&mut self, buffer: NonNull<u8>, context: isize);
+}
+
+/// The Rust side of a buffer factory set on an [InstantCamera].
+pub(crate) struct BufferAllocatorBox(Mutex<Box<dyn BufferAllocator>>);
+
+pub(crate) fn buffer_allocator_allocate(
+    allocator: &BufferAllocatorBox,
+    size: usize,
+    context: &mut isize,
+) -> Result<usize, PylonError> {
+    let mut allocator = allocator
+        .0
+        .lock()
+        .map_err(|_| PylonError::new("Buffer allocator is poisoned"))?;
+    let (buffer, buffer_context) = allocator.allocate(size)?;
+    *context = buffer_context;
+    Ok(buffer.as_ptr() as usize)
+}
+
+pub(crate) fn buffer_allocator_free(
+    allocator: &BufferAllocatorBox,
+    address: usize,
+    context: isize,
+) {
+    if let (Ok(mut allocator), Some(buffer)) =
+        (allocator.0.lock(), NonNull::new(address as *mut u8))
+    {
+        // Safety: pylon only frees buffers it got from `allocate`, once.
+        unsafe { allocator.free(buffer, context) }
+    }
+}
+
+impl InstantCamera<'_> {
+    /// Have pylon allocate the grab buffers with `allocator`.
+    ///
+    /// This must be called while the camera is not grabbing. The camera takes
+    /// ownership of the allocator and drops it after the last buffer has been
+    /// freed.
+    pub fn set_buffer_allocator<A>(&self, allocator: A) -> PylonResult<()>
+    where
+        A: BufferAllocator + 'static,
+    {
+        ffi::instant_camera_set_buffer_factory(
+            &self.inner,
+            Box::new(BufferAllocatorBox(Mutex::new(Box::new(allocator)))),
+        )
+        .into_rust()
+    }
+}
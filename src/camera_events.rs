@@ -0,0 +1,229 @@
+use crate::{ffi, ErrorKind, InstantCamera, InstantCameraRef, NodeMap, PylonError, PylonResult};
+
+/// An event sent by the camera, e.g. `ExposureEnd` or `FrameStartOvertrigger`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CameraEvent {
+    /// The name of the event as used for `EventSelector`.
+    pub name: String,
+    /// The camera time at which the event occurred, read from
+    /// `Event{name}Timestamp` or, on older cameras, `{name}EventTimestamp`, if
+    /// the event has this node.
+    pub timestamp: Option<i64>,
+    /// The frame the event refers to, read from `Event{name}FrameID` or
+    /// `{name}EventFrameID`, if the event has this node.
+    pub frame_id: Option<i64>,
+}
+
+impl CameraEvent {
+    fn read(name: &str, naming: EventNaming, node_map: &NodeMap) -> Self {
+        let read_integer = |node_name: String| {
+            if !node_map.has_node(&node_name) {
+                return None;
+            }
+            node_map.integer_node(&node_name).ok()?.value().ok()
+        };
+        Self {
+            name: name.to_string(),
+            timestamp: read_integer(naming.node_name(name, "Timestamp")),
+            frame_id: read_integer(naming.node_name(name, "FrameID")),
+        }
+    }
+}
+
+/// How the nodes of an event are named.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EventNaming {
+    /// `Event{name}Data`, as in SFNC 2.0 and later.
+    Sfnc2,
+    /// `{name}EventData`, as on older GigE cameras.
+    Legacy,
+}
+
+impl EventNaming {
+    /// Find the naming the camera uses for the event `name`.
+    fn of(node_map: &NodeMap, name: &str) -> PylonResult<Self> {
+        [EventNaming::Sfnc2, EventNaming::Legacy]
+            .iter()
+            .copied()
+            .find(|naming| node_map.has_node(&naming.node_name(name, "Data")))
+            .ok_or_else(|| {
+                PylonError::with_kind(
+                    ErrorKind::Unsupported,
+                    format!("The camera does not have a data node for event {}", name),
+                )
+            })
+    }
+
+    /// The name of the node holding `field`, e.g. `Timestamp`, of the event
+    /// `name`.
+    fn node_name(self, name: &str, field: &str) -> String {
+        match self {
+            EventNaming::Sfnc2 => format!("Event{}{}", name, field),
+            EventNaming::Legacy => format!("{}Event{}", name, field),
+        }
+    }
+}
+
+type CameraEventFn = dyn FnMut(&InstantCameraRef<'_>, CameraEvent) + Send;
+
+/// The Rust side of a camera event handler registered with an
+/// [InstantCamera].
+pub(crate) struct CameraEventHandlerBox {
+    name: String,
+    naming: EventNaming,
+    on_camera_event: Box<CameraEventFn>,
+}
+
+pub(crate) fn camera_event_handler_on_camera_event(
+    handler: &mut CameraEventHandlerBox,
+    camera: &ffi::CInstantCamera,
+) {
+    let camera = InstantCameraRef { inner: camera };
+    let event = match camera.node_map() {
+        Ok(node_map) => CameraEvent::read(&handler.name, handler.naming, &node_map),
+        Err(_) => CameraEvent {
+            name: handler.name.clone(),
+            timestamp: None,
+            frame_id: None,
+        },
+    };
+    (handler.on_camera_event)(&camera, event)
+}
+
+/// Keeps a camera event handler registered with an [InstantCamera].
+///
+/// Dropping this deregisters and drops the handler.
+pub struct CameraEventHandlerRegistration<'c> {
+    camera: &'c cxx::UniquePtr<ffi::CInstantCamera>,
+    handler: cxx::UniquePtr<ffi::RustCameraEventHandler>,
+    node_name: String,
+}
+
+impl Drop for CameraEventHandlerRegistration<'_> {
+    fn drop(&mut self) {
+        let _ = ffi::instant_camera_deregister_camera_event_handler(
+            self.camera,
+            &self.handler,
+            &self.node_name,
+        );
+    }
+}
+
+impl InstantCamera<'_> {
+    /// Have the camera send the event `name`, e.g. `ExposureEnd`.
+    ///
+    /// This selects the event with `EventSelector`, sets `EventNotification`
+    /// to `On` and enables `GrabCameraEvents` of the instant camera. The
+    /// camera must be open. Events are delivered while the camera is grabbing.
+    pub fn enable_camera_event(&self, name: &str) -> PylonResult<()> {
+        self.instant_camera_node_map()?
+            .boolean_node("GrabCameraEvents")?
+            .set_value(true)?;
+        let node_map = self.node_map()?;
+        node_map.enum_node("EventSelector")?.set_value(name)?;
+        node_map.enum_node("EventNotification")?.set_value("On")
+    }
+
+    /// Stop the camera from sending the event `name`.
+    pub fn disable_camera_event(&self, name: &str) -> PylonResult<()> {
+        let node_map = self.node_map()?;
+        node_map.enum_node("EventSelector")?.set_value(name)?;
+        node_map.enum_node("EventNotification")?.set_value("Off")
+    }
+
+    /// Call `f` whenever the camera sends the event `name`, e.g. `ExposureEnd`.
+    ///
+    /// The handler is registered for the `Event{name}Data` node or, on older
+    /// cameras, the `{name}EventData` node, so the camera must be open. An
+    /// error of kind [ErrorKind::Unsupported] is returned if the camera has
+    /// neither. The event must also be enabled with
+    /// [InstantCamera::enable_camera_event]. `f` is called from a pylon thread
+    /// and must not panic; a panic aborts the process. The handler stays
+    /// registered until the returned registration is dropped.
+    pub fn on_camera_event<F>(
+        &self,
+        name: &str,
+        f: F,
+    ) -> PylonResult<CameraEventHandlerRegistration<'_>>
+    where
+        F: FnMut(&InstantCameraRef<'_>, CameraEvent) + Send + 'static,
+    {
+        let naming = EventNaming::of(&self.node_map()?, name)?;
+        let node_name = naming.node_name(name, "Data");
+        let handler = ffi::new_camera_event_handler(Box::new(CameraEventHandlerBox {
+            name: name.to_string(),
+            naming,
+            on_camera_event: Box::new(f),
+        }));
+        ffi::instant_camera_register_camera_event_handler(&self.inner, &handler, &node_name)?;
+        Ok(CameraEventHandlerRegistration {
+            camera: &self.inner,
+            handler,
+            node_name,
+        })
+    }
+
+    /// Receive the event `name` as an async stream.
+    ///
+    /// See [InstantCamera::on_camera_event]. The handler stays registered
+    /// until the stream is dropped.
+    #[cfg(feature = "stream")]
+    pub fn camera_event_stream(&self, name: &str) -> PylonResult<CameraEventStream<'_>> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let registration = self.on_camera_event(name, move |_camera, event| {
+            let _ = tx.send(event);
+        })?;
+        Ok(CameraEventStream {
+            _registration: registration,
+            events: tokio_stream::wrappers::UnboundedReceiverStream::new(rx),
+        })
+    }
+}
+
+/// The stream of events returned by [InstantCamera::camera_event_stream].
+#[cfg(feature = "stream")]
+pub struct CameraEventStream<'c> {
+    _registration: CameraEventHandlerRegistration<'c>,
+    events: tokio_stream::wrappers::UnboundedReceiverStream<CameraEvent>,
+}
+
+#[cfg(feature = "stream")]
+impl tokio_stream::Stream for CameraEventStream<'_> {
+    type Item = CameraEvent;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<CameraEvent>> {
+        std::pin::Pin::new(&mut self.events).poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EventNaming;
+
+    #[test]
+    fn sfnc2_node_names() {
+        assert_eq!(
+            EventNaming::Sfnc2.node_name("ExposureEnd", "Data"),
+            "EventExposureEndData"
+        );
+        assert_eq!(
+            EventNaming::Sfnc2.node_name("ExposureEnd", "FrameID"),
+            "EventExposureEndFrameID"
+        );
+    }
+
+    #[test]
+    fn legacy_node_names() {
+        assert_eq!(
+            EventNaming::Legacy.node_name("ExposureEnd", "Data"),
+            "ExposureEndEventData"
+        );
+        assert_eq!(
+            EventNaming::Legacy.node_name("ExposureEnd", "Timestamp"),
+            "ExposureEndEventTimestamp"
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use std::convert::TryFrom;
+use std::time::{Duration, Instant};
+
+use crate::{
+    GrabOptions, GrabResult, GrabWorker, InstantCamera, OverflowPolicy, PylonError, PylonResult,
+};
+
+/// How a [CameraGroup] decides which grab results belong together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchBy {
+    /// The value of an integer chunk, e.g. `ChunkCounterValue` or
+    /// `ChunkTriggerinputcounter`. The chunk must be enabled on all cameras.
+    Chunk(String),
+    /// The block ID, i.e. the frame ID the camera assigned.
+    BlockId,
+    /// The camera timestamp, with results at most `tolerance` ticks apart
+    /// belonging together. The camera clocks must be synchronized, e.g. with
+    /// PTP.
+    TimeStamp { tolerance: u64 },
+}
+
+impl MatchBy {
+    fn key(&self, grab_result: &GrabResult) -> PylonResult<u64> {
+        grab_result.check_succeeded()?;
+        match self {
+            MatchBy::Chunk(name) => {
+                let value = grab_result
+                    .chunk_data_node_map()?
+                    .integer_node(name)?
+                    .value()?;
+                chunk_key(name, value)
+            }
+            MatchBy::BlockId => grab_result.block_id(),
+            MatchBy::TimeStamp { .. } => grab_result.time_stamp(),
+        }
+    }
+
+    fn tolerance(&self) -> u64 {
+        match self {
+            MatchBy::TimeStamp { tolerance } => *tolerance,
+            _ => 0,
+        }
+    }
+}
+
+/// The grab results of a [CameraGroup] which belong together.
+#[derive(Clone)]
+pub struct FrameSet {
+    /// The smallest key of the grab results, see [MatchBy].
+    pub key: u64,
+    /// The grab results in the order of the cameras passed to
+    /// [CameraGroup::start]. A camera whose result is missing has `None`.
+    pub grab_results: Vec<Option<GrabResult>>,
+}
+
+impl FrameSet {
+    /// Return whether the set has a grab result of every camera.
+    pub fn is_complete(&self) -> bool {
+        self.grab_results.iter().all(Option::is_some)
+    }
+}
+
+/// Why [CameraGroup::recv_timeout] did not return a set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecvTimeoutError {
+    /// No set was complete within the timeout.
+    Timeout,
+    /// All cameras stopped grabbing and all their results have been
+    /// delivered.
+    Finished,
+}
+
+impl std::fmt::Display for RecvTimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RecvTimeoutError::Timeout => write!(f, "timed out waiting for a frame set"),
+            RecvTimeoutError::Finished => write!(f, "all cameras stopped grabbing"),
+        }
+    }
+}
+
+impl std::error::Error for RecvTimeoutError {}
+
+/// Grabs from several cameras and delivers their grab results as matched
+/// [FrameSet]s, e.g. for stereo or multi-view rigs.
+///
+/// Each camera is grabbed by a [GrabWorker]. Sets are delivered in the order
+/// of their keys. If a camera has no result for a key, e.g. because an image
+/// was lost or the camera stopped grabbing before the others, an incomplete
+/// set is delivered.
+pub struct CameraGroup<'a> {
+    workers: Vec<GrabWorker<'a>>,
+    match_by: MatchBy,
+    /// The oldest unmatched result of each camera and its key.
+    heads: Vec<Option<(u64, GrabResult)>>,
+    complete_sets: u64,
+    incomplete_sets: u64,
+}
+
+impl<'a> CameraGroup<'a> {
+    /// Start grabbing from all cameras with the same options.
+    ///
+    /// `capacity` and `policy` apply to the queue of each camera, see
+    /// [GrabWorker::spawn].
+    pub fn start(
+        cameras: Vec<InstantCamera<'a>>,
+        options: &GrabOptions,
+        capacity: usize,
+        policy: OverflowPolicy,
+        match_by: MatchBy,
+    ) -> PylonResult<Self> {
+        let workers = cameras
+            .into_iter()
+            .map(|camera| GrabWorker::spawn(camera, options, capacity, policy))
+            .collect::<PylonResult<Vec<_>>>()?;
+        let heads = workers.iter().map(|_| None).collect();
+        Ok(Self {
+            workers,
+            match_by,
+            heads,
+            complete_sets: 0,
+            incomplete_sets: 0,
+        })
+    }
+
+    /// Wait for the next set.
+    ///
+    /// Returns `None` once all cameras stopped grabbing and all their results
+    /// have been delivered. A failed grab is returned as an error, after which
+    /// the group can be used further.
+    pub fn recv(&mut self) -> Option<PylonResult<FrameSet>> {
+        // Without a deadline, there is no timeout.
+        self.next_set(None).ok()
+    }
+
+    /// Like [CameraGroup::recv], but waits at most `timeout` for the results
+    /// of the next set. Results which did arrive are kept for the next call.
+    pub fn recv_timeout(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<PylonResult<FrameSet>, RecvTimeoutError> {
+        self.next_set(Some(Instant::now() + timeout))
+    }
+
+    /// The number of complete sets delivered.
+    pub fn complete_sets(&self) -> u64 {
+        self.complete_sets
+    }
+
+    /// The number of incomplete sets delivered.
+    pub fn incomplete_sets(&self) -> u64 {
+        self.incomplete_sets
+    }
+
+    /// The grab workers, e.g. to read their dropped frame counters.
+    pub fn workers(&self) -> &[GrabWorker<'a>] {
+        &self.workers
+    }
+
+    /// Stop grabbing and return the cameras.
+    pub fn stop(self) -> PylonResult<Vec<InstantCamera<'a>>> {
+        self.workers.into_iter().map(GrabWorker::stop).collect()
+    }
+
+    fn next_set(
+        &mut self,
+        deadline: Option<Instant>,
+    ) -> Result<PylonResult<FrameSet>, RecvTimeoutError> {
+        for (worker, head) in self.workers.iter().zip(self.heads.iter_mut()) {
+            if head.is_some() {
+                continue;
+            }
+            let received = match deadline {
+                None => worker.recv(),
+                Some(deadline) => {
+                    worker.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                }
+            };
+            let grab_result = match received {
+                Some(Ok(grab_result)) => grab_result,
+                Some(Err(e)) => return Ok(Err(e)),
+                // The results of the other cameras are delivered in
+                // incomplete sets.
+                None if worker.is_finished() && worker.queue_depth() == 0 => continue,
+                None => return Err(RecvTimeoutError::Timeout),
+            };
+            match self.match_by.key(&grab_result) {
+                Ok(key) => *head = Some((key, grab_result)),
+                Err(e) => return Ok(Err(e)),
+            }
+        }
+
+        let (key, grab_results) = take_set(&mut self.heads, self.match_by.tolerance())
+            .ok_or(RecvTimeoutError::Finished)?;
+        let set = FrameSet { key, grab_results };
+        if set.is_complete() {
+            self.complete_sets += 1;
+        } else {
+            self.incomplete_sets += 1;
+        }
+        Ok(Ok(set))
+    }
+}
+
+fn chunk_key(name: &str, value: i64) -> PylonResult<u64> {
+    u64::try_from(value).map_err(|_| {
+        PylonError::new(format!(
+            "Chunk {} has the negative value {}, which cannot be matched",
+            name, value
+        ))
+    })
+}
+
+/// Take the results whose keys are within `tolerance` of the smallest key
+/// out of `heads`, together with the smallest key. The others are matched
+/// with later results. Returns `None` if `heads` is empty.
+fn take_set<T>(heads: &mut [Option<(u64, T)>], tolerance: u64) -> Option<(u64, Vec<Option<T>>)> {
+    let min = heads.iter().flatten().map(|(key, _)| *key).min()?;
+    let results = heads
+        .iter_mut()
+        .map(|head| match head {
+            Some((key, _)) if *key - min <= tolerance => head.take().map(|(_, result)| result),
+            _ => None,
+        })
+        .collect();
+    Some((min, results))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chunk_key, take_set};
+
+    #[test]
+    fn matching_results_form_a_set() {
+        let mut heads = vec![Some((7, "a")), Some((7, "b"))];
+        assert_eq!(
+            take_set(&mut heads, 0),
+            Some((7, vec![Some("a"), Some("b")]))
+        );
+        assert_eq!(heads, [None, None]);
+    }
+
+    #[test]
+    fn later_results_are_kept() {
+        let mut heads = vec![Some((7, "a")), Some((9, "b")), Some((8, "c"))];
+        assert_eq!(
+            take_set(&mut heads, 1),
+            Some((7, vec![Some("a"), None, Some("c")]))
+        );
+        assert_eq!(heads, [None, Some((9, "b")), None]);
+    }
+
+    #[test]
+    fn finished_cameras_leave_gaps() {
+        let mut heads = vec![None, Some((3, "b"))];
+        assert_eq!(take_set(&mut heads, 0), Some((3, vec![None, Some("b")])));
+        assert_eq!(take_set(&mut heads, 0), None);
+    }
+
+    #[test]
+    fn negative_chunk_values_are_rejected() {
+        assert_eq!(chunk_key("ChunkCounterValue", 42).unwrap(), 42);
+        assert!(chunk_key("ChunkCounterValue", -1).is_err());
+    }
+}
@@ -0,0 +1,327 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::{ErrorKind, InstantCamera, PylonError, PylonResult};
+
+/// The latch command and value nodes, by standard feature naming convention
+/// and by the older GigE names.
+const LATCH_NODES: [(&str, &str); 2] = [
+    ("TimestampLatch", "TimestampLatchValue"),
+    ("GevTimestampControlLatch", "GevTimestampValue"),
+];
+
+/// The timestamps of cameras without `GevTimestampTickFrequency` count
+/// nanoseconds.
+const DEFAULT_TICK_FREQUENCY: f64 = 1e9;
+
+const DEFAULT_MAX_SAMPLES: usize = 32;
+
+/// A host time converted from a camera timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HostTime {
+    pub instant: Instant,
+    pub system_time: SystemTime,
+    /// An estimate of how far the conversion may be off.
+    pub error: Duration,
+}
+
+/// A camera timestamp latched together with the host time.
+struct Sample {
+    ticks: i64,
+    /// Seconds since `ClockSync::origin`.
+    host: f64,
+    /// Half the time it took to latch and read the timestamp.
+    uncertainty: f64,
+}
+
+/// The camera time in seconds since the first sample, mapped linearly to
+/// the host time.
+struct Fit {
+    offset: f64,
+    slope: f64,
+    /// The standard deviation of the samples from the fit.
+    residual: f64,
+}
+
+/// Maps camera timestamps, e.g. [GrabResult::time_stamp](crate::GrabResult::time_stamp),
+/// to the host clock.
+///
+/// Each [ClockSync::sample] latches the camera timestamp and records it
+/// together with the host time. The offset and drift between the clocks are
+/// fitted over the most recent samples, so sampling regularly, e.g. once per
+/// second, keeps the mapping accurate over time.
+pub struct ClockSync {
+    latch_command: &'static str,
+    latch_value: &'static str,
+    tick_frequency: f64,
+    max_samples: usize,
+    origin: Instant,
+    origin_system_time: SystemTime,
+    samples: VecDeque<Sample>,
+    fit: Option<Fit>,
+}
+
+impl ClockSync {
+    /// Prepare clock synchronization for an open camera and take the first
+    /// sample.
+    pub fn new(camera: &InstantCamera) -> PylonResult<Self> {
+        let node_map = camera.node_map()?;
+        let (latch_command, latch_value) = LATCH_NODES
+            .iter()
+            .copied()
+            .find(|(command, _)| node_map.has_node(command))
+            .ok_or_else(|| {
+                PylonError::with_kind(
+                    ErrorKind::Unsupported,
+                    "The camera has neither TimestampLatch nor GevTimestampControlLatch",
+                )
+            })?;
+        let tick_frequency = if node_map.has_node("GevTimestampTickFrequency") {
+            node_map
+                .integer_node("GevTimestampTickFrequency")?
+                .value()? as f64
+        } else {
+            DEFAULT_TICK_FREQUENCY
+        };
+        let mut clock_sync = Self {
+            latch_command,
+            latch_value,
+            tick_frequency,
+            max_samples: DEFAULT_MAX_SAMPLES,
+            origin: Instant::now(),
+            origin_system_time: SystemTime::now(),
+            samples: VecDeque::new(),
+            fit: None,
+        };
+        clock_sync.sample(camera)?;
+        Ok(clock_sync)
+    }
+
+    /// The number of most recent samples the fit uses. The default is 32.
+    pub fn max_samples(self, max_samples: usize) -> Self {
+        Self {
+            max_samples: max_samples.max(1),
+            ..self
+        }
+    }
+
+    /// Latch the camera timestamp and update the fit.
+    pub fn sample(&mut self, camera: &InstantCamera) -> PylonResult<()> {
+        let node_map = camera.node_map()?;
+        let command = node_map.command_node(self.latch_command)?;
+        let before = Instant::now();
+        command.execute(true)?;
+        let after = Instant::now();
+        let ticks = node_map.integer_node(self.latch_value)?.value()?;
+
+        let uncertainty = (after - before).as_secs_f64() / 2.0;
+        let host = (before - self.origin).as_secs_f64() + uncertainty;
+        self.add_sample(Sample {
+            ticks,
+            host,
+            uncertainty,
+        });
+        Ok(())
+    }
+
+    fn add_sample(&mut self, sample: Sample) {
+        while self.samples.len() >= self.max_samples {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+        self.fit = Some(self.fit_samples());
+    }
+
+    /// The nominal number of camera timestamp ticks per second.
+    pub fn tick_frequency(&self) -> f64 {
+        self.tick_frequency
+    }
+
+    /// How much faster the camera clock runs than the host clock, in parts
+    /// per million. Needs at least two samples.
+    pub fn drift_ppm(&self) -> Option<f64> {
+        if self.samples.len() < 2 {
+            return None;
+        }
+        Some((1.0 / self.fit.as_ref()?.slope - 1.0) * 1e6)
+    }
+
+    /// Convert a camera timestamp to host time.
+    ///
+    /// Returns `None` if the time cannot be represented by `Instant`.
+    pub fn to_host_time(&self, ticks: u64) -> Option<HostTime> {
+        let fit = self.fit.as_ref()?;
+        let host = fit.offset + fit.slope * self.camera_seconds(i128::from(ticks));
+        let since_origin = Duration::try_from_secs_f64(host.abs()).ok()?;
+        let (instant, system_time) = if host >= 0.0 {
+            (
+                self.origin.checked_add(since_origin)?,
+                self.origin_system_time.checked_add(since_origin)?,
+            )
+        } else {
+            (
+                self.origin.checked_sub(since_origin)?,
+                self.origin_system_time.checked_sub(since_origin)?,
+            )
+        };
+        let uncertainty = self
+            .samples
+            .iter()
+            .map(|sample| sample.uncertainty)
+            .fold(0.0, f64::max);
+        Some(HostTime {
+            instant,
+            system_time,
+            error: Duration::from_secs_f64(uncertainty + fit.residual),
+        })
+    }
+
+    /// The camera time in seconds since the first retained sample.
+    fn camera_seconds(&self, ticks: i128) -> f64 {
+        let first = self.samples.front().map_or(0, |sample| sample.ticks);
+        (ticks - i128::from(first)) as f64 / self.tick_frequency
+    }
+
+    /// Fit the host time to the camera time by least squares.
+    fn fit_samples(&self) -> Fit {
+        let n = self.samples.len() as f64;
+        let points: Vec<(f64, f64)> = self
+            .samples
+            .iter()
+            .map(|sample| (self.camera_seconds(i128::from(sample.ticks)), sample.host))
+            .collect();
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let sxx: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        let sxy: f64 = points
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum();
+        // With a single sample or no camera time elapsed, assume the clocks
+        // run at the same rate.
+        let slope = if sxx > 0.0 { sxy / sxx } else { 1.0 };
+        let offset = mean_y - slope * mean_x;
+        let residual = if points.len() > 2 {
+            let sum_squares: f64 = points
+                .iter()
+                .map(|(x, y)| (y - offset - slope * x).powi(2))
+                .sum();
+            (sum_squares / (n - 2.0)).sqrt()
+        } else {
+            0.0
+        };
+        Fit {
+            offset,
+            slope,
+            residual,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TICKS_PER_SECOND: i64 = 1_000_000_000;
+
+    /// A clock sync with samples of `(camera ticks, host seconds)`.
+    fn clock_sync(samples: &[(i64, f64)]) -> ClockSync {
+        let mut clock_sync = ClockSync {
+            latch_command: LATCH_NODES[0].0,
+            latch_value: LATCH_NODES[0].1,
+            tick_frequency: DEFAULT_TICK_FREQUENCY,
+            max_samples: DEFAULT_MAX_SAMPLES,
+            origin: Instant::now(),
+            origin_system_time: SystemTime::now(),
+            samples: VecDeque::new(),
+            fit: None,
+        };
+        for &(ticks, host) in samples {
+            clock_sync.add_sample(Sample {
+                ticks,
+                host,
+                uncertainty: 0.0,
+            });
+        }
+        clock_sync
+    }
+
+    fn host_seconds(clock_sync: &ClockSync, host_time: &HostTime) -> f64 {
+        host_time
+            .instant
+            .duration_since(clock_sync.origin)
+            .as_secs_f64()
+    }
+
+    #[test]
+    fn offset_and_drift_are_fitted() {
+        // The camera clock starts at 5 s when the host is at 2 s and runs
+        // 100 ppm fast.
+        let rate = 1.0 + 100e-6;
+        let samples: Vec<(i64, f64)> = (0..10)
+            .map(|i| {
+                (
+                    5 * TICKS_PER_SECOND + i * TICKS_PER_SECOND,
+                    2.0 + i as f64 / rate,
+                )
+            })
+            .collect();
+        let clock_sync = clock_sync(&samples);
+
+        let drift_ppm = clock_sync.drift_ppm().unwrap();
+        assert!((drift_ppm - 100.0).abs() < 1e-3, "{}", drift_ppm);
+
+        let ticks = 25 * TICKS_PER_SECOND;
+        let host_time = clock_sync.to_host_time(ticks as u64).unwrap();
+        let expected = 2.0 + 20.0 / rate;
+        assert!((host_seconds(&clock_sync, &host_time) - expected).abs() < 1e-6);
+        assert!(host_time.error < Duration::from_micros(1));
+    }
+
+    #[test]
+    fn ticks_above_i64_max_are_converted() {
+        let clock_sync = clock_sync(&[(i64::MAX - TICKS_PER_SECOND, 1.0)]);
+        let ticks = i64::MAX as u64 + TICKS_PER_SECOND as u64;
+        let host_time = clock_sync.to_host_time(ticks).unwrap();
+        assert!((host_seconds(&clock_sync, &host_time) - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn single_sample_assumes_no_drift() {
+        let clock_sync = clock_sync(&[(3 * TICKS_PER_SECOND, 1.5)]);
+        assert_eq!(clock_sync.drift_ppm(), None);
+
+        let host_time = clock_sync
+            .to_host_time(4 * TICKS_PER_SECOND as u64)
+            .unwrap();
+        assert!((host_seconds(&clock_sync, &host_time) - 2.5).abs() < 1e-6);
+        assert_eq!(host_time.error, Duration::ZERO);
+    }
+
+    #[test]
+    fn noise_is_reported_as_error() {
+        let samples = [
+            (0, 1.0),
+            (TICKS_PER_SECOND, 2.001),
+            (2 * TICKS_PER_SECOND, 2.999),
+            (3 * TICKS_PER_SECOND, 4.001),
+        ];
+        let host_time = clock_sync(&samples).to_host_time(0).unwrap();
+        assert!(host_time.error > Duration::from_micros(100));
+        assert!(host_time.error < Duration::from_millis(2));
+    }
+
+    #[test]
+    fn only_the_most_recent_samples_are_fitted() {
+        let mut clock_sync = clock_sync(&[(0, 100.0)]).max_samples(2);
+        for i in 1..=2 {
+            clock_sync.add_sample(Sample {
+                ticks: i * TICKS_PER_SECOND,
+                host: i as f64,
+                uncertainty: 0.0,
+            });
+        }
+        assert_eq!(clock_sync.samples.len(), 2);
+        assert!(clock_sync.drift_ppm().unwrap().abs() < 1e-6);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    ffi, BuiltinConfiguration, CxxResultExt, InstantCamera, InstantCameraRef, PylonError,
+    PylonResult, RegistrationMode,
+};
+
+/// A reusable camera configuration, applied each time the camera is opened.
+///
+/// Register it with [InstantCamera::register_configuration].
+pub trait Configuration: Send {
+    /// Apply the configuration to the camera which has just been opened.
+    ///
+    /// An error makes [InstantCamera::open] fail. This must not panic; a
+    /// panic aborts the process.
+    fn configure(&mut self, camera: &InstantCameraRef<'_>) -> PylonResult<()>;
+}
+
+/// The Rust side of a [Configuration] registered with an [InstantCamera].
+pub(crate) struct ConfigurationBox(Box<dyn Configuration>);
+
+pub(crate) fn configuration_on_opened(
+    configuration: &mut ConfigurationBox,
+    camera: &ffi::CInstantCamera,
+) -> Result<(), PylonError> {
+    let camera = InstantCameraRef { inner: camera };
+    configuration.0.configure(&camera)
+}
+
+/// Hooks called by an [InstantCamera] on changes of its state.
+///
+/// All methods default to doing nothing. They are called from the thread
+/// causing the change, e.g. the thread calling [InstantCamera::open], except
+/// for [ConfigurationEventHandler::on_camera_device_removed] and
+/// [ConfigurationEventHandler::on_grab_error], which may be called from a pylon
+/// thread. The methods must not panic; a panic aborts the process.
+pub trait ConfigurationEventHandler: Send {
+    /// Called before a device is attached to the camera.
+    fn on_attach(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called after a device has been attached to the camera.
+    fn on_attached(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called before the device is detached from the camera.
+    fn on_detach(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called after the device has been detached from the camera.
+    fn on_detached(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called before the device is destroyed.
+    fn on_destroy(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called after the device has been destroyed.
+    fn on_destroyed(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called before the device is opened.
+    fn on_open(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called after the device has been opened. This is the place to apply
+    /// camera settings which must be restored whenever the camera is opened.
+    fn on_opened(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called before the device is closed.
+    fn on_close(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called after the device has been closed.
+    fn on_closed(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called before grabbing starts.
+    fn on_grab_start(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called after grabbing has started.
+    fn on_grab_started(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called before grabbing stops.
+    fn on_grab_stop(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called after grabbing has stopped.
+    fn on_grab_stopped(&mut self, _camera: &InstantCameraRef<'_>) {}
+    /// Called when an error occurs in pylon's grab loop thread.
+    fn on_grab_error(&mut self, _camera: &InstantCameraRef<'_>, _message: &str) {}
+    /// Called when the camera device has been removed, e.g. because the cable
+    /// was unplugged or the GigE heartbeat expired.
+    fn on_camera_device_removed(&mut self, _camera: &InstantCameraRef<'_>) {}
+}
+
+/// The Rust side of a configuration event handler registered with an
+/// [InstantCamera].
+pub(crate) struct ConfigurationEventHandlerBox(Box<dyn ConfigurationEventHandler>);
+
+pub(crate) fn configuration_event_handler_on_event(
+    handler: &mut ConfigurationEventHandlerBox,
+    camera: &ffi::CInstantCamera,
+    event: ffi::ConfigurationEvent,
+) {
+    use ffi::ConfigurationEvent as E;
+
+    let camera = InstantCameraRef { inner: camera };
+    let handler = &mut handler.0;
+    match event {
+        E::Attach => handler.on_attach(&camera),
+        E::Attached => handler.on_attached(&camera),
+        E::Detach => handler.on_detach(&camera),
+        E::Detached => handler.on_detached(&camera),
+        E::Destroy => handler.on_destroy(&camera),
+        E::Destroyed => handler.on_destroyed(&camera),
+        E::Open => handler.on_open(&camera),
+        E::Opened => handler.on_opened(&camera),
+        E::Close => handler.on_close(&camera),
+        E::Closed => handler.on_closed(&camera),
+        E::GrabStart => handler.on_grab_start(&camera),
+        E::GrabStarted => handler.on_grab_started(&camera),
+        E::GrabStop => handler.on_grab_stop(&camera),
+        E::GrabStopped => handler.on_grab_stopped(&camera),
+        E::CameraDeviceRemoved => handler.on_camera_device_removed(&camera),
+        _ => {}
+    }
+}
+
+pub(crate) fn configuration_event_handler_on_grab_error(
+    handler: &mut ConfigurationEventHandlerBox,
+    camera: &ffi::CInstantCamera,
+    message: &str,
+) {
+    let camera = InstantCameraRef { inner: camera };
+    handler.0.on_grab_error(&camera, message)
+}
+
+struct CameraDeviceRemoved<F>(F);
+
+impl<F: FnMut() + Send> ConfigurationEventHandler for CameraDeviceRemoved<F> {
+    fn on_camera_device_removed(&mut self, _camera: &InstantCameraRef<'_>) {
+        (self.0)()
+    }
+}
+
+/// Keeps an event handler registered with an [InstantCamera].
+///
+/// Dropping this deregisters and drops the handler.
+pub struct ConfigurationEventHandlerRegistration<'c> {
+    camera: &'c cxx::UniquePtr<ffi::CInstantCamera>,
+    handler: cxx::UniquePtr<ffi::RustConfigurationEventHandler>,
+}
+
+impl Drop for ConfigurationEventHandlerRegistration<'_> {
+    fn drop(&mut self) {
+        let _ =
+            ffi::instant_camera_deregister_configuration_event_handler(self.camera, &self.handler);
+    }
+}
+
+impl InstantCamera<'_> {
+    /// Register one of pylon's standard configurations.
+    ///
+    /// The configuration is applied when the camera is opened, so register it
+    /// before calling [InstantCamera::open]. Use [RegistrationMode::ReplaceAll]
+    /// to remove the continuous acquisition configuration pylon registers by
+    /// default. The configuration stays registered until it is replaced or
+    /// the camera is dropped.
+    pub fn register_builtin_configuration(
+        &self,
+        mode: RegistrationMode,
+        configuration: BuiltinConfiguration,
+    ) -> PylonResult<()> {
+        ffi::instant_camera_register_builtin_configuration(&self.inner, configuration, mode)
+            .into_rust()
+    }
+
+    /// Register a configuration applied each time the camera is opened.
+    ///
+    /// Like [InstantCamera::register_builtin_configuration], the camera takes
+    /// ownership of the configuration and drops it when it is replaced or the
+    /// camera is dropped.
+    pub fn register_configuration<C>(
+        &self,
+        mode: RegistrationMode,
+        configuration: C,
+    ) -> PylonResult<()>
+    where
+        C: Configuration + 'static,
+    {
+        ffi::instant_camera_register_configuration(
+            &self.inner,
+            Box::new(ConfigurationBox(Box::new(configuration))),
+            mode,
+        )
+        .into_rust()
+    }
+
+    /// Register hooks called on changes of the camera's state.
+    ///
+    /// With [RegistrationMode::ReplaceAll], the configurations registered
+    /// before are removed, including the default configuration of pylon which
+    /// sets up continuous acquisition. The handler stays registered until the
+    /// returned registration is dropped.
+    pub fn register_configuration_event_handler<H>(
+        &self,
+        mode: RegistrationMode,
+        handler: H,
+    ) -> PylonResult<ConfigurationEventHandlerRegistration<'_>>
+    where
+        H: ConfigurationEventHandler + 'static,
+    {
+        let handler = ffi::new_configuration_event_handler(Box::new(ConfigurationEventHandlerBox(
+            Box::new(handler),
+        )));
+        ffi::instant_camera_register_configuration_event_handler(&self.inner, &handler, mode)?;
+        Ok(ConfigurationEventHandlerRegistration {
+            camera: &self.inner,
+            handler,
+        })
+    }
+
+    /// Call `f` when the camera device has been removed, e.g. because the
+    /// cable was unplugged or the GigE heartbeat expired.
+    ///
+    /// `f` is called from a pylon thread. The handler stays registered, also
+    /// across [InstantCamera::reconnect], until the returned registration is
+    /// dropped.
+    pub fn on_camera_device_removed<F>(
+        &self,
+        f: F,
+    ) -> PylonResult<ConfigurationEventHandlerRegistration<'_>>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.register_configuration_event_handler(RegistrationMode::Append, CameraDeviceRemoved(f))
+    }
+}
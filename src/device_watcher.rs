@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::{
+    ConfigurationEventHandlerRegistration, DeviceInfo, InstantCamera, Pylon, PylonResult, TlFactory,
+};
+
+/// A change in the set of available devices, as reported by [DeviceWatcher].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeviceEvent {
+    /// A device became available.
+    Arrived(DeviceInfo),
+    /// A device is no longer available.
+    Removed(DeviceInfo),
+}
+
+type SendFn = dyn FnMut(DeviceEvent) -> bool + Send;
+
+/// The state shared by the watcher thread and the removal handlers of
+/// watched cameras.
+struct Shared {
+    /// The available devices by full name.
+    known: HashMap<String, DeviceInfo>,
+    send: Box<SendFn>,
+    /// Set once the receiving side of the events has been dropped.
+    closed: bool,
+}
+
+impl Shared {
+    fn send(&mut self, event: DeviceEvent) {
+        if !self.closed && !(self.send)(event) {
+            self.closed = true;
+        }
+    }
+}
+
+/// Watch for devices being plugged in or removed.
+///
+/// A background thread enumerates the devices every `poll_interval` and
+/// reports the differences to the previous enumeration. Devices are identified
+/// by their full name. Devices which are present when the watcher is created
+/// are reported as [DeviceEvent::Arrived]. If an enumeration fails, it is
+/// retried after the next interval.
+///
+/// Removal of an open camera is detected by pylon much earlier than by
+/// enumerating, e.g. through the GigE heartbeat. Pass such cameras to
+/// [DeviceWatcher::watch_camera] to report their removal as soon as pylon
+/// notices it.
+///
+/// The thread keeps the pylon runtime initialized while it runs. It stops when
+/// the watcher is dropped or when the receiving side of the events is dropped.
+pub struct DeviceWatcher {
+    shared: Arc<Mutex<Shared>>,
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+/// The stream of events of a [DeviceWatcher].
+#[cfg(feature = "stream")]
+pub type DeviceEventStream = tokio_stream::wrappers::UnboundedReceiverStream<DeviceEvent>;
+
+impl DeviceWatcher {
+    /// Start watching and deliver the events on a channel.
+    pub fn new(poll_interval: Duration) -> PylonResult<(Self, mpsc::Receiver<DeviceEvent>)> {
+        let (tx, rx) = mpsc::channel();
+        let watcher = Self::spawn(poll_interval, move |event| tx.send(event).is_ok())?;
+        Ok((watcher, rx))
+    }
+
+    /// Start watching and deliver the events as an async stream.
+    #[cfg(feature = "stream")]
+    pub fn new_stream(poll_interval: Duration) -> PylonResult<(Self, DeviceEventStream)> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let watcher = Self::spawn(poll_interval, move |event| tx.send(event).is_ok())?;
+        Ok((watcher, DeviceEventStream::new(rx)))
+    }
+
+    /// Report the removal of `camera` as soon as pylon detects it, using
+    /// [InstantCamera::on_camera_device_removed].
+    ///
+    /// The camera is watched until the returned registration is dropped.
+    pub fn watch_camera<'c>(
+        &self,
+        camera: &'c InstantCamera,
+    ) -> PylonResult<ConfigurationEventHandlerRegistration<'c>> {
+        let full_name = camera.device_info().full_name()?;
+        let shared = self.shared.clone();
+        camera.on_camera_device_removed(move || {
+            let mut shared = lock(&shared);
+            // The device is reported only once, whether the enumeration or
+            // pylon notices the removal first.
+            if let Some(device) = shared.known.remove(&full_name) {
+                shared.send(DeviceEvent::Removed(device));
+            }
+        })
+    }
+
+    fn spawn<F>(poll_interval: Duration, send: F) -> PylonResult<Self>
+    where
+        F: FnMut(DeviceEvent) -> bool + Send + 'static,
+    {
+        let shared = Arc::new(Mutex::new(Shared {
+            known: HashMap::new(),
+            send: Box::new(send),
+            closed: false,
+        }));
+        let thread_shared = shared.clone();
+        let (stop_tx, stop_rx) = mpsc::channel();
+        let thread = std::thread::Builder::new()
+            .name("pylon-device-watcher".into())
+            .spawn(move || {
+                let pylon = Pylon::new();
+                let tl_factory = TlFactory::instance(&pylon);
+                loop {
+                    if let Ok(current) = enumerate_by_full_name(&tl_factory) {
+                        let mut shared = lock(&thread_shared);
+                        let (removed, arrived) = diff(&shared.known, &current);
+                        for device in removed {
+                            shared.send(DeviceEvent::Removed(device));
+                        }
+                        for device in arrived {
+                            shared.send(DeviceEvent::Arrived(device));
+                        }
+                        shared.known = current;
+                        if shared.closed {
+                            return;
+                        }
+                    }
+                    match stop_rx.recv_timeout(poll_interval) {
+                        Err(mpsc::RecvTimeoutError::Timeout) => {}
+                        _ => return,
+                    }
+                }
+            })?;
+        Ok(Self {
+            shared,
+            stop: Some(stop_tx),
+            thread: Some(thread),
+        })
+    }
+}
+
+impl Drop for DeviceWatcher {
+    fn drop(&mut self) {
+        // Dropping the sender wakes up the thread.
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn lock(shared: &Mutex<Shared>) -> std::sync::MutexGuard<'_, Shared> {
+    shared.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn enumerate_by_full_name(tl_factory: &TlFactory) -> PylonResult<HashMap<String, DeviceInfo>> {
+    tl_factory
+        .enumerate_devices()?
+        .into_iter()
+        .map(|device| Ok((device.full_name()?, device)))
+        .collect()
+}
+
+/// The devices which were removed from and which arrived in `current`
+/// compared to `known`.
+fn diff<K, V>(known: &HashMap<K, V>, current: &HashMap<K, V>) -> (Vec<V>, Vec<V>)
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    let removed = known
+        .iter()
+        .filter(|(name, _)| !current.contains_key(name))
+        .map(|(_, device)| device.clone())
+        .collect();
+    let arrived = current
+        .iter()
+        .filter(|(name, _)| !known.contains_key(name))
+        .map(|(_, device)| device.clone())
+        .collect();
+    (removed, arrived)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff;
+    use std::collections::HashMap;
+
+    fn devices(names: &[&'static str]) -> HashMap<&'static str, &'static str> {
+        names.iter().map(|name| (*name, *name)).collect()
+    }
+
+    fn sorted(mut devices: Vec<&'static str>) -> Vec<&'static str> {
+        devices.sort_unstable();
+        devices
+    }
+
+    #[test]
+    fn initial_devices_arrive() {
+        let (removed, arrived) = diff(&devices(&[]), &devices(&["a", "b"]));
+        assert!(removed.is_empty());
+        assert_eq!(sorted(arrived), ["a", "b"]);
+    }
+
+    #[test]
+    fn changes_are_reported() {
+        let (removed, arrived) = diff(&devices(&["a", "b"]), &devices(&["b", "c"]));
+        assert_eq!(removed, ["a"]);
+        assert_eq!(arrived, ["c"]);
+    }
+
+    #[test]
+    fn unchanged_devices_are_not_reported() {
+        let (removed, arrived) = diff(&devices(&["a", "b"]), &devices(&["a", "b"]));
+        assert!(removed.is_empty());
+        assert!(arrived.is_empty());
+    }
+}
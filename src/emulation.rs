@@ -0,0 +1,139 @@
+//! Support for pylon's camera emulation transport layer.
+//!
+//! Emulated cameras allow developing and testing without camera hardware. See
+//! [Basler's documentation](https://docs.baslerweb.com/camera-emulation.html).
+
+use std::path::PathBuf;
+
+use crate::{
+    lock_runtime, path_to_string, DeviceInfo, InstantCamera, Pylon, PylonError, PylonResult,
+    TlFactory,
+};
+
+/// The device class of emulated cameras.
+const CAMEMU_DEVICE_CLASS: &str = "BaslerCamEmu";
+
+/// The environment variable holding the number of emulated cameras.
+const PYLON_CAMEMU: &str = "PYLON_CAMEMU";
+
+/// The test images an emulated camera can generate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestImage {
+    Testimage1,
+    Testimage2,
+    Testimage3,
+    Testimage4,
+    Testimage5,
+    Testimage6,
+}
+
+impl TestImage {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TestImage::Testimage1 => "Testimage1",
+            TestImage::Testimage2 => "Testimage2",
+            TestImage::Testimage3 => "Testimage3",
+            TestImage::Testimage4 => "Testimage4",
+            TestImage::Testimage5 => "Testimage5",
+            TestImage::Testimage6 => "Testimage6",
+        }
+    }
+}
+
+/// Where an emulated camera takes its images from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmulatedImageSource {
+    /// Generate a test image.
+    TestImage(TestImage),
+    /// Load the images from a file or from all image files in a directory.
+    Images(PathBuf),
+}
+
+impl Pylon {
+    /// Initialize the pylon runtime with `count` emulated cameras.
+    ///
+    /// pylon reads the number of emulated cameras from the `PYLON_CAMEMU`
+    /// environment variable when the runtime is initialized. Setting
+    /// `PYLON_CAMEMU` before starting the process and using [Pylon::new] is
+    /// preferable.
+    ///
+    /// Returns an error if the runtime is already initialized by another
+    /// [Pylon] instance with a different number of emulated cameras.
+    ///
+    /// # Safety
+    ///
+    /// If the runtime is not initialized yet and `PYLON_CAMEMU` differs from
+    /// `count`, this sets `PYLON_CAMEMU`. Modifying the environment is only
+    /// sound while no other thread reads or writes it, so this must be called
+    /// while the process is single-threaded, e.g. at the start of `main`.
+    pub unsafe fn with_emulated_devices(count: u32) -> PylonResult<Self> {
+        let mut runtime = lock_runtime();
+        if runtime.handles > 0 {
+            if runtime.emulated_devices != count {
+                return Err(PylonError::new(format!(
+                    "The pylon runtime is already initialized with {} emulated cameras",
+                    runtime.emulated_devices
+                )));
+            }
+        } else if emulated_devices_from_env() != count {
+            std::env::set_var(PYLON_CAMEMU, count.to_string());
+        }
+        Ok(Self::initialize(&mut runtime))
+    }
+}
+
+/// The number of emulated cameras pylon creates according to the
+/// environment.
+pub(crate) fn emulated_devices_from_env() -> u32 {
+    std::env::var(PYLON_CAMEMU)
+        .ok()
+        .and_then(|count| count.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+impl<'a> TlFactory<'a> {
+    /// Enumerate only the emulated cameras.
+    pub fn enumerate_emulated_devices(&self) -> PylonResult<Vec<DeviceInfo>> {
+        let mut emulated = vec![];
+        for device in self.enumerate_devices()? {
+            if device.device_class()? == CAMEMU_DEVICE_CLASS {
+                emulated.push(device);
+            }
+        }
+        Ok(emulated)
+    }
+
+    /// Create an instant camera object with the emulated camera found first.
+    pub fn create_first_emulated_device(&self) -> PylonResult<InstantCamera<'a>> {
+        match self.enumerate_emulated_devices()?.first() {
+            Some(device) => self.create_device(device),
+            None => Err(PylonError::new(
+                "No emulated camera found, set the PYLON_CAMEMU environment variable",
+            )),
+        }
+    }
+}
+
+impl InstantCamera<'_> {
+    /// Select the images an emulated camera delivers. The camera must be open.
+    pub fn set_emulated_image_source(&self, source: &EmulatedImageSource) -> PylonResult<()> {
+        let node_map = self.node_map()?;
+        match source {
+            EmulatedImageSource::TestImage(test_image) => {
+                node_map.enum_node("ImageFileMode")?.set_value("Off")?;
+                node_map
+                    .enum_node("TestImageSelector")?
+                    .set_value(test_image.as_str())?;
+            }
+            EmulatedImageSource::Images(path) => {
+                let filename = path_to_string(path)?;
+                node_map.enum_node("TestImageSelector")?.set_value("Off")?;
+                node_map.enum_node("ImageFileMode")?.set_value("On")?;
+                node_map
+                    .string_node("ImageFilename")?
+                    .set_value(&filename)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{GrabResult, PylonResult};
+
+/// The image of a successful grab.
+///
+/// A frame keeps its grab buffer alive, so its data can be read without
+/// copying and without error handling. Frames are `Send` and `Sync` and can be
+/// cloned or put in an `Arc` to hand the same image to several threads.
+#[derive(Clone)]
+pub struct Frame {
+    grab_result: GrabResult,
+    width: u32,
+    height: u32,
+    block_id: u64,
+    time_stamp: u64,
+    data: *const u8,
+    len: usize,
+}
+
+// `data` points into the grab buffer, which is kept alive and unchanged by
+// `grab_result`.
+unsafe impl Send for Frame {}
+unsafe impl Sync for Frame {}
+
+impl Frame {
+    /// Create a frame from a grab result, failing if the grab did not
+    /// succeed.
+    pub fn new(grab_result: GrabResult) -> PylonResult<Self> {
+        grab_result.check_succeeded()?;
+        let buffer = grab_result.buffer()?;
+        let (data, len) = (buffer.as_ptr(), buffer.len());
+        Ok(Self {
+            width: grab_result.width()?,
+            height: grab_result.height()?,
+            block_id: grab_result.block_id()?,
+            time_stamp: grab_result.time_stamp()?,
+            data,
+            len,
+            grab_result,
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn block_id(&self) -> u64 {
+        self.block_id
+    }
+
+    pub fn time_stamp(&self) -> u64 {
+        self.time_stamp
+    }
+
+    /// The image data.
+    pub fn data(&self) -> &[u8] {
+        // Safety: see the `Send` and `Sync` implementations.
+        unsafe { std::slice::from_raw_parts(self.data, self.len) }
+    }
+
+    /// The grab result, e.g. to access its chunk data.
+    pub fn grab_result(&self) -> &GrabResult {
+        &self.grab_result
+    }
+
+    pub fn into_grab_result(self) -> GrabResult {
+        self.grab_result
+    }
+}
+
+impl std::convert::TryFrom<GrabResult> for Frame {
+    type Error = crate::PylonError;
+
+    fn try_from(grab_result: GrabResult) -> PylonResult<Self> {
+        Self::new(grab_result)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{GrabResult, InstantCamera, PylonResult, TimeoutHandling};
+
+/// An iterator over the grab results of a camera, see [InstantCamera::frames].
+pub struct Frames<'c> {
+    camera: &'c InstantCamera<'c>,
+    timeout_ms: u32,
+    grab_result: Option<GrabResult>,
+}
+
+impl InstantCamera<'_> {
+    /// Iterate over the grab results until the camera stops grabbing.
+    ///
+    /// Each result is waited for at most `timeout_ms`; a timeout is returned
+    /// as an error. The iterator ends when [InstantCamera::is_grabbing]
+    /// returns false, e.g. after the number of images passed to
+    /// [GrabOptions::count](crate::GrabOptions::count) has been grabbed.
+    pub fn frames(&self, timeout_ms: u32) -> Frames<'_> {
+        Frames {
+            camera: self,
+            timeout_ms,
+            grab_result: None,
+        }
+    }
+}
+
+impl Frames<'_> {
+    /// Retrieve the next grab result into a grab result owned by the
+    /// iterator, which is reused for each result and allocates nothing.
+    pub fn next_ref(&mut self) -> Option<PylonResult<&GrabResult>> {
+        if self.grab_result.is_none() {
+            match GrabResult::new() {
+                Ok(grab_result) => self.grab_result = Some(grab_result),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        let grab_result = self.grab_result.as_mut()?;
+        match retrieve(self.camera, self.timeout_ms, grab_result)? {
+            Ok(()) => Some(Ok(grab_result)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+impl Iterator for Frames<'_> {
+    type Item = PylonResult<GrabResult>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut grab_result = match GrabResult::new() {
+            Ok(grab_result) => grab_result,
+            Err(e) => return Some(Err(e)),
+        };
+        match retrieve(self.camera, self.timeout_ms, &mut grab_result)? {
+            Ok(()) => Some(Ok(grab_result)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// Retrieve the next grab result, or `None` once the camera stopped grabbing.
+fn retrieve(
+    camera: &InstantCamera,
+    timeout_ms: u32,
+    grab_result: &mut GrabResult,
+) -> Option<PylonResult<()>> {
+    while camera.is_grabbing() {
+        match camera.retrieve_result(timeout_ms, grab_result, TimeoutHandling::ThrowException) {
+            Ok(true) => return Some(Ok(())),
+            // Grabbing was stopped while waiting.
+            Ok(false) => continue,
+            Err(e) => return Some(Err(e)),
+        }
+    }
+    None
+}
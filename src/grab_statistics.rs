@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::{GrabResult, InstantCamera, PylonResult};
+
+/// The block ID of grab results from transport layers without block IDs.
+const NO_BLOCK_ID: u64 = u64::MAX;
+
+/// The upper bound of the smallest bucket of the interval histogram. Each
+/// further bucket is twice as wide as the previous one.
+const FIRST_BUCKET: Duration = Duration::from_micros(500);
+const NUM_BUCKETS: usize = 16;
+
+/// Frame loss and throughput statistics of a sequence of grab results.
+///
+/// Feed it grab results with [GrabStatistics::record], or let a camera record
+/// the results it retrieves with [InstantCamera::enable_grab_statistics].
+/// Results which pylon's grab loop thread delivers to an image event handler,
+/// see [GrabLoop::ProvidedByInstantCamera](crate::GrabLoop), are not retrieved
+/// by the camera and have to be recorded by the handler. Intervals, frame
+/// rate and bandwidth are measured with the host clock when a result is
+/// recorded.
+#[derive(Debug, Clone, Default)]
+pub struct GrabStatistics {
+    succeeded: u64,
+    failures: BTreeMap<u32, u64>,
+    missing_block_ids: u64,
+    skipped_images: u64,
+    bytes: u64,
+    last_block_id: Option<u64>,
+    first_arrival: Option<Instant>,
+    last_arrival: Option<Instant>,
+    intervals: IntervalStatistics,
+}
+
+#[derive(Debug, Clone, Default)]
+struct IntervalStatistics {
+    count: u64,
+    min: Option<Duration>,
+    max: Option<Duration>,
+    // Running mean and sum of squared deviations in seconds (Welford).
+    mean: f64,
+    m2: f64,
+    buckets: [u64; NUM_BUCKETS],
+}
+
+impl IntervalStatistics {
+    fn record(&mut self, interval: Duration) {
+        self.count += 1;
+        self.min = Some(self.min.map_or(interval, |min| min.min(interval)));
+        self.max = Some(self.max.map_or(interval, |max| max.max(interval)));
+        let secs = interval.as_secs_f64();
+        let delta = secs - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (secs - self.mean);
+
+        let mut bound = FIRST_BUCKET;
+        let mut bucket = 0;
+        while interval > bound && bucket < NUM_BUCKETS - 1 {
+            bound *= 2;
+            bucket += 1;
+        }
+        self.buckets[bucket] += 1;
+    }
+}
+
+impl GrabStatistics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a grab result to the statistics.
+    pub fn record(&mut self, grab_result: &GrabResult) -> PylonResult<()> {
+        let arrival = Instant::now();
+        let block_id = grab_result.block_id()?;
+        if block_id != NO_BLOCK_ID {
+            // A block ID which does not increase means that grabbing was
+            // restarted or that the ID wrapped around.
+            if let Some(last) = self.last_block_id {
+                if block_id > last + 1 {
+                    self.missing_block_ids += block_id - last - 1;
+                }
+            }
+            self.last_block_id = Some(block_id);
+        }
+
+        if !grab_result.grab_succeeded()? {
+            *self.failures.entry(grab_result.error_code()?).or_insert(0) += 1;
+            return Ok(());
+        }
+        self.succeeded += 1;
+        self.skipped_images += grab_result.number_of_skipped_images()? as u64;
+        self.bytes += u64::from(grab_result.payload_size()?);
+        if let Some(last) = self.last_arrival {
+            self.intervals
+                .record(arrival.saturating_duration_since(last));
+        }
+        self.first_arrival.get_or_insert(arrival);
+        self.last_arrival = Some(arrival);
+        Ok(())
+    }
+
+    /// The number of successful grabs.
+    pub fn frames(&self) -> u64 {
+        self.succeeded
+    }
+
+    /// The number of failed grabs.
+    pub fn failed(&self) -> u64 {
+        self.failures.values().sum()
+    }
+
+    /// The number of failed grabs by pylon error code.
+    pub fn failures_by_error_code(&self) -> &BTreeMap<u32, u64> {
+        &self.failures
+    }
+
+    /// The number of block IDs missing between consecutive grab results,
+    /// i.e. images which were lost before reaching the host.
+    pub fn missing_block_ids(&self) -> u64 {
+        self.missing_block_ids
+    }
+
+    /// The number of images pylon skipped, see
+    /// [GrabResult::number_of_skipped_images].
+    pub fn skipped_images(&self) -> u64 {
+        self.skipped_images
+    }
+
+    /// The total payload size of the successful grabs in bytes.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// The time between the first and the last successful grab.
+    pub fn elapsed(&self) -> Option<Duration> {
+        Some(
+            self.last_arrival?
+                .saturating_duration_since(self.first_arrival?),
+        )
+        .filter(|elapsed| !elapsed.is_zero())
+    }
+
+    /// The measured frame rate in frames per second.
+    pub fn frame_rate(&self) -> Option<f64> {
+        Some(self.intervals.count as f64 / self.elapsed()?.as_secs_f64())
+    }
+
+    /// The measured bandwidth in bytes per second.
+    pub fn bandwidth(&self) -> Option<f64> {
+        let bytes_per_frame = self.bytes as f64 / self.succeeded as f64;
+        Some(self.frame_rate()? * bytes_per_frame)
+    }
+
+    pub fn min_interval(&self) -> Option<Duration> {
+        self.intervals.min
+    }
+
+    pub fn max_interval(&self) -> Option<Duration> {
+        self.intervals.max
+    }
+
+    pub fn mean_interval(&self) -> Option<Duration> {
+        if self.intervals.count == 0 {
+            return None;
+        }
+        Some(Duration::from_secs_f64(self.intervals.mean))
+    }
+
+    /// The standard deviation of the intervals between successful grabs.
+    pub fn interval_jitter(&self) -> Option<Duration> {
+        if self.intervals.count < 2 {
+            return None;
+        }
+        let variance = self.intervals.m2 / (self.intervals.count - 1) as f64;
+        Some(Duration::from_secs_f64(variance.sqrt()))
+    }
+
+    /// A histogram of the intervals between successful grabs as pairs of
+    /// the upper bound of a bucket and the number of intervals in it.
+    ///
+    /// The bounds start at 0.5 ms and double with each bucket. The last
+    /// bucket has no upper bound and is reported with `Duration::MAX`.
+    pub fn interval_histogram(&self) -> Vec<(Duration, u64)> {
+        let mut bound = FIRST_BUCKET;
+        let mut histogram = Vec::with_capacity(NUM_BUCKETS);
+        for (bucket, count) in self.intervals.buckets.iter().enumerate() {
+            if bucket == NUM_BUCKETS - 1 {
+                histogram.push((Duration::MAX, *count));
+            } else {
+                histogram.push((bound, *count));
+            }
+            bound *= 2;
+        }
+        histogram
+    }
+
+    /// Clear the statistics.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl InstantCamera<'_> {
+    /// Record statistics of the grab results retrieved by
+    /// [InstantCamera::retrieve_result], and therefore also by
+    /// [InstantCamera::frames], the grab result streams and a
+    /// [GrabWorker](crate::GrabWorker), and by [InstantCamera::grab_one].
+    /// Results delivered by pylon's grab loop thread are not recorded, see
+    /// [GrabStatistics]. Enabling them again resets them.
+    pub fn enable_grab_statistics(&self) {
+        self.state
+            .grab_statistics
+            .replace(Some(Arc::new(Mutex::new(GrabStatistics::new()))));
+    }
+
+    pub fn disable_grab_statistics(&self) {
+        self.state.grab_statistics.replace(None);
+    }
+
+    /// A snapshot of the grab statistics, if enabled.
+    pub fn grab_statistics(&self) -> Option<GrabStatistics> {
+        let statistics = self.shared_grab_statistics()?;
+        let snapshot = lock(&statistics).clone();
+        Some(snapshot)
+    }
+
+    /// The statistics shared with a [GrabWorker](crate::GrabWorker).
+    pub(crate) fn shared_grab_statistics(&self) -> Option<Arc<Mutex<GrabStatistics>>> {
+        self.state.grab_statistics.borrow().clone()
+    }
+
+    pub(crate) fn record_grab_statistics(&self, grab_result: &GrabResult) {
+        if let Some(statistics) = self.state.grab_statistics.borrow().as_ref() {
+            // A grab result whose properties cannot be read is not counted.
+            let _ = lock(statistics).record(grab_result);
+        }
+    }
+}
+
+pub(crate) fn lock(statistics: &Mutex<GrabStatistics>) -> MutexGuard<'_, GrabStatistics> {
+    statistics.lock().unwrap_or_else(|e| e.into_inner())
+}
@@ -0,0 +1,43 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio_stream::Stream;
+
+use crate::{GrabOptions, GrabResult, InstantCamera, PylonResult};
+
+/// An async stream of the grab results of a camera.
+///
+/// Unlike the stream implemented by [InstantCamera] itself, this only
+/// borrows the camera immutably, so its parameters can be accessed while the
+/// stream is consumed. Grabbing stops when the stream is dropped.
+pub struct GrabStream<'c> {
+    camera: &'c InstantCamera<'c>,
+}
+
+impl InstantCamera<'_> {
+    /// Start grabbing and return the grab results as an async stream.
+    pub fn grab_stream(&self, options: &GrabOptions) -> PylonResult<GrabStream<'_>> {
+        self.start_grabbing(options)?;
+        Ok(GrabStream { camera: self })
+    }
+}
+
+impl GrabStream<'_> {
+    pub fn camera(&self) -> &InstantCamera<'_> {
+        self.camera
+    }
+}
+
+impl Stream for GrabStream<'_> {
+    type Item = PylonResult<GrabResult>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.camera.poll_grab_result(cx)
+    }
+}
+
+impl Drop for GrabStream<'_> {
+    fn drop(&mut self) {
+        let _ = self.camera.stop_grabbing();
+    }
+}
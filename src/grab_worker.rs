@@ -0,0 +1,327 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::{
+    grab_statistics, DetachedCamera, ErrorKind, GrabOptions, GrabResult, GrabStatistics,
+    InstantCamera, Pylon, PylonError, PylonResult, TimeoutHandling,
+};
+
+/// How long the grab thread waits for a result before checking whether it
+/// should stop.
+const POLL_INTERVAL_MS: u32 = 100;
+
+/// What a [GrabWorker] does with a new grab result when its queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Wait until a result has been received. While waiting, images pile up
+    /// in pylon's output queue and, once its buffers run out, are dropped
+    /// by pylon.
+    Block,
+    /// Drop the new result.
+    DropNewest,
+    /// Drop the oldest queued result to make room for the new one.
+    DropOldest,
+}
+
+struct QueueState {
+    items: VecDeque<PylonResult<GrabResult>>,
+    dropped: u64,
+    /// Set by the worker to stop the grab thread.
+    stopping: bool,
+    /// Set by the grab thread once it stopped grabbing.
+    finished: bool,
+}
+
+/// A bounded queue of grab results shared by the grab thread and receivers.
+struct FrameQueue {
+    state: Mutex<QueueState>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+    policy: OverflowPolicy,
+}
+
+impl FrameQueue {
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        // The lock is never held while running user code, so it cannot be
+        // poisoned by a panic in a receiver.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn push(&self, item: PylonResult<GrabResult>) {
+        let mut state = self.lock();
+        while state.items.len() >= self.capacity {
+            match self.policy {
+                OverflowPolicy::Block => {
+                    if state.stopping {
+                        return;
+                    }
+                    state = self.not_full.wait(state).unwrap_or_else(|e| e.into_inner());
+                }
+                OverflowPolicy::DropNewest => {
+                    state.dropped += 1;
+                    return;
+                }
+                OverflowPolicy::DropOldest => {
+                    state.items.pop_front();
+                    state.dropped += 1;
+                }
+            }
+        }
+        state.items.push_back(item);
+        self.not_empty.notify_one();
+    }
+
+    fn pop(&self, deadline: Option<Instant>) -> Option<PylonResult<GrabResult>> {
+        let mut state = self.lock();
+        loop {
+            if let Some(item) = state.items.pop_front() {
+                self.not_full.notify_one();
+                return Some(item);
+            }
+            if state.finished {
+                return None;
+            }
+            state = match deadline {
+                None => self
+                    .not_empty
+                    .wait(state)
+                    .unwrap_or_else(|e| e.into_inner()),
+                Some(deadline) => {
+                    let timeout = deadline.checked_duration_since(Instant::now())?;
+                    self.not_empty
+                        .wait_timeout(state, timeout)
+                        .unwrap_or_else(|e| e.into_inner())
+                        .0
+                }
+            };
+        }
+    }
+
+    fn is_stopping(&self) -> bool {
+        self.lock().stopping
+    }
+
+    fn stop(&self) {
+        self.lock().stopping = true;
+        self.not_full.notify_all();
+    }
+
+    fn finish(&self) {
+        self.lock().finished = true;
+        self.not_empty.notify_all();
+    }
+}
+
+/// Receives the grab results of a [GrabWorker], possibly on another thread.
+#[derive(Clone)]
+pub struct GrabReceiver {
+    queue: Arc<FrameQueue>,
+}
+
+impl GrabReceiver {
+    /// Wait for the next grab result.
+    ///
+    /// Returns `None` once the worker stopped grabbing and all queued results
+    /// have been received.
+    pub fn recv(&self) -> Option<PylonResult<GrabResult>> {
+        self.queue.pop(None)
+    }
+
+    /// Like [GrabReceiver::recv], but also returns `None` if no result
+    /// arrives within `timeout`.
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<PylonResult<GrabResult>> {
+        self.queue.pop(Some(Instant::now() + timeout))
+    }
+
+    /// Return the next grab result if one is queued.
+    pub fn try_recv(&self) -> Option<PylonResult<GrabResult>> {
+        self.queue.pop(Some(Instant::now()))
+    }
+
+    /// The number of grab results dropped because the queue was full.
+    pub fn dropped_frames(&self) -> u64 {
+        self.queue.lock().dropped
+    }
+
+    /// The number of grab results waiting to be received.
+    pub fn queue_depth(&self) -> usize {
+        self.queue.lock().items.len()
+    }
+
+    /// Return whether the worker stopped grabbing, e.g. because the number of
+    /// images passed to [GrabOptions::count] has been grabbed.
+    pub fn is_finished(&self) -> bool {
+        self.queue.lock().finished
+    }
+}
+
+/// Runs the grab loop of a camera on a dedicated thread and delivers the grab
+/// results over a bounded queue.
+///
+/// The worker owns the camera while it is running. [GrabWorker::stop] stops
+/// grabbing and returns the camera. Dropping the worker stops grabbing and
+/// closes the camera.
+///
+/// If the camera device is removed, the worker delivers an error of kind
+/// [ErrorKind::DeviceRemoved]. If a [ReconnectPolicy](crate::ReconnectPolicy)
+/// is set, it then reconnects and keeps grabbing, otherwise it stops. While
+/// the worker waits for the camera to reappear, stopping it blocks until the
+/// camera reappeared or the timeout of the policy expired.
+pub struct GrabWorker<'a> {
+    receiver: GrabReceiver,
+    thread: Option<JoinHandle<(DetachedCamera, PylonResult<()>)>>,
+    statistics: Option<Arc<Mutex<GrabStatistics>>>,
+    lib: &'a Pylon,
+}
+
+impl<'a> GrabWorker<'a> {
+    /// Start grabbing and spawn the grab thread.
+    ///
+    /// At most `capacity` results are queued; `policy` decides what happens
+    /// to further results until they are received.
+    pub fn spawn(
+        camera: InstantCamera<'a>,
+        options: &GrabOptions,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> PylonResult<Self> {
+        if capacity == 0 {
+            return Err(PylonError::new("The queue capacity must be at least 1"));
+        }
+        camera.start_grabbing(options)?;
+
+        let queue = Arc::new(FrameQueue {
+            state: Mutex::new(QueueState {
+                items: VecDeque::with_capacity(capacity),
+                dropped: 0,
+                stopping: false,
+                finished: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity,
+            policy,
+        });
+        let statistics = camera.shared_grab_statistics();
+        let lib = camera._lib;
+        let pylon = lib.retain();
+        let detached = DetachedCamera::new(camera);
+        let thread_queue = queue.clone();
+        let thread = std::thread::Builder::new()
+            .name("pylon-grab-worker".into())
+            .spawn(move || grab_loop(pylon, detached, &thread_queue))
+            .map_err(|e| PylonError::new(format!("Could not spawn grab thread: {}", e)))?;
+        Ok(Self {
+            receiver: GrabReceiver { queue },
+            thread: Some(thread),
+            statistics,
+            lib,
+        })
+    }
+
+    /// Wait for the next grab result, see [GrabReceiver::recv].
+    pub fn recv(&self) -> Option<PylonResult<GrabResult>> {
+        self.receiver.recv()
+    }
+
+    /// See [GrabReceiver::recv_timeout].
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<PylonResult<GrabResult>> {
+        self.receiver.recv_timeout(timeout)
+    }
+
+    /// See [GrabReceiver::try_recv].
+    pub fn try_recv(&self) -> Option<PylonResult<GrabResult>> {
+        self.receiver.try_recv()
+    }
+
+    pub fn dropped_frames(&self) -> u64 {
+        self.receiver.dropped_frames()
+    }
+
+    pub fn queue_depth(&self) -> usize {
+        self.receiver.queue_depth()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.receiver.is_finished()
+    }
+
+    /// A snapshot of the grab statistics, if they were enabled with
+    /// [InstantCamera::enable_grab_statistics] before spawning the worker.
+    pub fn grab_statistics(&self) -> Option<GrabStatistics> {
+        Some(grab_statistics::lock(self.statistics.as_ref()?).clone())
+    }
+
+    /// A receiver which can be moved to another thread.
+    pub fn receiver(&self) -> GrabReceiver {
+        self.receiver.clone()
+    }
+
+    /// Stop grabbing and return the camera. Results which have not been
+    /// received yet are discarded.
+    pub fn stop(mut self) -> PylonResult<InstantCamera<'a>> {
+        let (camera, stopped) = self.join()?;
+        stopped?;
+        Ok(camera.attach(self.lib))
+    }
+
+    fn join(&mut self) -> PylonResult<(DetachedCamera, PylonResult<()>)> {
+        self.receiver.queue.stop();
+        let thread = self
+            .thread
+            .take()
+            .ok_or_else(|| PylonError::new("Grab thread already joined"))?;
+        thread
+            .join()
+            .map_err(|_| PylonError::new("Grab thread panicked"))
+    }
+}
+
+impl Drop for GrabWorker<'_> {
+    fn drop(&mut self) {
+        if self.thread.is_some() {
+            // The camera is dropped here, while `self.lib` is still borrowed.
+            let _ = self.join();
+        }
+    }
+}
+
+fn grab_loop(
+    pylon: Pylon,
+    detached: DetachedCamera,
+    queue: &FrameQueue,
+) -> (DetachedCamera, PylonResult<()>) {
+    let camera = detached.attach(&pylon);
+    while camera.is_grabbing() && !queue.is_stopping() {
+        let mut grab_result = match GrabResult::new() {
+            Ok(grab_result) => grab_result,
+            Err(e) => {
+                queue.push(Err(e));
+                break;
+            }
+        };
+        match camera.retrieve_result(POLL_INTERVAL_MS, &mut grab_result, TimeoutHandling::Return) {
+            Ok(true) => queue.push(Ok(grab_result)),
+            Ok(false) => {}
+            Err(e) => {
+                let removed = e.kind() == ErrorKind::DeviceRemoved;
+                queue.push(Err(e));
+                if removed {
+                    if !camera.has_reconnect_policy() {
+                        break;
+                    }
+                    if let Err(e) = camera.reconnect() {
+                        queue.push(Err(e));
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    let stopped = camera.stop_grabbing();
+    queue.finish();
+    (DetachedCamera::new(camera), stopped)
+}
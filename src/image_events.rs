@@ -0,0 +1,68 @@
+use crate::{ffi, GrabResult, InstantCamera, InstantCameraRef, PylonResult, RegistrationMode};
+
+type ImageGrabbedFn = dyn FnMut(&InstantCameraRef<'_>, GrabResult) + Send;
+
+/// The Rust side of an image event handler registered with an
+/// [InstantCamera].
+pub(crate) struct ImageEventHandlerBox {
+    on_image_grabbed: Box<ImageGrabbedFn>,
+}
+
+pub(crate) fn image_event_handler_on_image_grabbed(
+    handler: &mut ImageEventHandlerBox,
+    camera: &ffi::CInstantCamera,
+    grab_result: cxx::UniquePtr<ffi::CGrabResultPtr>,
+) {
+    let camera = InstantCameraRef { inner: camera };
+    (handler.on_image_grabbed)(&camera, GrabResult { inner: grab_result })
+}
+
+/// Keeps an image event handler registered with an [InstantCamera].
+///
+/// Dropping this deregisters and drops the handler. pylon waits for a running
+/// call of the handler to return before the deregistration completes.
+pub struct ImageEventHandlerRegistration<'c> {
+    camera: &'c cxx::UniquePtr<ffi::CInstantCamera>,
+    handler: cxx::UniquePtr<ffi::RustImageEventHandler>,
+}
+
+impl Drop for ImageEventHandlerRegistration<'_> {
+    fn drop(&mut self) {
+        let _ = ffi::instant_camera_deregister_image_event_handler(self.camera, &self.handler);
+    }
+}
+
+impl InstantCamera<'_> {
+    /// Call `f` with every grab result.
+    ///
+    /// To have `f` called from pylon's grab loop thread, start grabbing with
+    /// [GrabLoop::ProvidedByInstantCamera](crate::GrabLoop). When grabbing with
+    /// [GrabLoop::ProvidedByUser](crate::GrabLoop), `f` is called from
+    /// [InstantCamera::retrieve_result] before it returns.
+    ///
+    /// `f` receives its own reference to the grab result. Holding on to it
+    /// keeps the buffer from being requeued, so the camera may run out of
+    /// buffers. `f` must not panic; a panic aborts the process.
+    ///
+    /// With [RegistrationMode::ReplaceAll], handlers registered before are
+    /// removed. Their registrations stay valid and dropping them has no
+    /// effect on the camera. The handler stays registered until the returned
+    /// registration is dropped.
+    pub fn register_image_event_handler<F>(
+        &self,
+        mode: RegistrationMode,
+        f: F,
+    ) -> PylonResult<ImageEventHandlerRegistration<'_>>
+    where
+        F: FnMut(&InstantCameraRef<'_>, GrabResult) + Send + 'static,
+    {
+        let handler = ffi::new_image_event_handler(Box::new(ImageEventHandlerBox {
+            on_image_grabbed: Box::new(f),
+        }));
+        ffi::instant_camera_register_image_event_handler(&self.inner, &handler, mode)?;
+        Ok(ImageEventHandlerRegistration {
+            camera: &self.inner,
+            handler,
+        })
+    }
+}
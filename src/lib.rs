@@ -11,8 +11,8 @@ pub mod stream_unix;
 /// DEPRECATED: the `stream` module exposes no public items and should not be used externally.
 pub use crate::stream_unix as stream;
 
-#[cfg(feature = "stream")]
 use std::cell::RefCell;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 #[cfg(all(target_os = "windows", feature = "stream"))]
 use std::thread::JoinHandle;
@@ -20,8 +20,77 @@ use std::thread::JoinHandle;
 #[cfg(all(target_os = "windows", feature = "stream"))]
 mod stream_windows;
 
+#[cfg(feature = "stream")]
+mod blocking;
+
+mod buffer_allocator;
+pub use buffer_allocator::BufferAllocator;
+use buffer_allocator::{buffer_allocator_allocate, buffer_allocator_free, BufferAllocatorBox};
+
+mod camera_events;
+#[cfg(feature = "stream")]
+pub use camera_events::CameraEventStream;
+use camera_events::{camera_event_handler_on_camera_event, CameraEventHandlerBox};
+pub use camera_events::{CameraEvent, CameraEventHandlerRegistration};
+
+mod camera_group;
+pub use camera_group::{CameraGroup, FrameSet, MatchBy, RecvTimeoutError};
+
+mod clock_sync;
+pub use clock_sync::{ClockSync, HostTime};
+
+mod configuration;
+use configuration::{
+    configuration_event_handler_on_event, configuration_event_handler_on_grab_error,
+    configuration_on_opened, ConfigurationBox, ConfigurationEventHandlerBox,
+};
+pub use configuration::{
+    Configuration, ConfigurationEventHandler, ConfigurationEventHandlerRegistration,
+};
+
+mod device_watcher;
+#[cfg(feature = "stream")]
+pub use device_watcher::DeviceEventStream;
+pub use device_watcher::{DeviceEvent, DeviceWatcher};
+
+mod emulation;
+pub use emulation::{EmulatedImageSource, TestImage};
+
+mod frame;
+pub use frame::Frame;
+
+mod frames;
+pub use frames::Frames;
+
+mod grab_statistics;
+pub use grab_statistics::GrabStatistics;
+
+#[cfg(feature = "stream")]
+mod grab_stream;
+#[cfg(feature = "stream")]
+pub use grab_stream::GrabStream;
+
+mod grab_worker;
+pub use grab_worker::{GrabReceiver, GrabWorker, OverflowPolicy};
+
+mod image_events;
+pub use image_events::ImageEventHandlerRegistration;
+use image_events::{image_event_handler_on_image_grabbed, ImageEventHandlerBox};
+
+mod ptp;
+pub use ptp::{wait_for_ptp_lock, PtpState, PtpStatus};
+
+mod reconnect;
+pub use reconnect::ReconnectPolicy;
+
+mod software_trigger;
+
+mod stream_statistics;
+pub use stream_statistics::StreamStatistics;
+
 #[derive(Debug)]
 pub struct PylonError {
+    kind: ErrorKind,
     msg: String,
     #[cfg(feature = "backtrace")]
     backtrace: Backtrace,
@@ -30,6 +99,7 @@ pub struct PylonError {
 impl From<cxx::Exception> for PylonError {
     fn from(orig: cxx::Exception) -> PylonError {
         PylonError {
+            kind: ErrorKind::Pylon,
             msg: orig.what().into(),
             #[cfg(feature = "backtrace")]
             backtrace: Backtrace::capture(),
@@ -40,6 +110,7 @@ impl From<cxx::Exception> for PylonError {
 impl From<std::str::Utf8Error> for PylonError {
     fn from(_: std::str::Utf8Error) -> PylonError {
         PylonError {
+            kind: ErrorKind::Other,
             msg: "Cannot convert C++ string to UTF-8".to_string(),
             #[cfg(feature = "backtrace")]
             backtrace: Backtrace::capture(),
@@ -50,6 +121,7 @@ impl From<std::str::Utf8Error> for PylonError {
 impl From<std::io::Error> for PylonError {
     fn from(orig: std::io::Error) -> PylonError {
         PylonError {
+            kind: ErrorKind::Io,
             msg: orig.to_string(),
             #[cfg(feature = "backtrace")]
             backtrace: Backtrace::capture(),
@@ -57,6 +129,47 @@ impl From<std::io::Error> for PylonError {
     }
 }
 
+impl PylonError {
+    fn new<S: Into<String>>(msg: S) -> PylonError {
+        Self::with_kind(ErrorKind::Other, msg)
+    }
+
+    fn with_kind<S: Into<String>>(kind: ErrorKind, msg: S) -> PylonError {
+        PylonError {
+            kind,
+            msg: msg.into(),
+            #[cfg(feature = "backtrace")]
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+/// The kind of a [PylonError].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// pylon threw an exception.
+    Pylon,
+    /// A grab did not succeed, see [GrabResult::error_code].
+    GrabFailed,
+    /// An I/O error occurred, e.g. while waiting for grab results.
+    Io,
+    /// An async stream was polled outside of a tokio runtime without the
+    /// `async-io` feature.
+    NoAsyncRuntime,
+    /// The camera device has been removed, see [InstantCamera::reconnect].
+    DeviceRemoved,
+    /// The camera does not support a feature, e.g. because it lacks the
+    /// nodes for it.
+    Unsupported,
+    /// Any other error.
+    Other,
+}
+
 impl std::fmt::Display for PylonError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "PylonError({})", self.msg)
@@ -86,10 +199,88 @@ mod ffi {
         LatestImages,
         UpcomingImage,
     }
+    /// Who runs the loop retrieving grab results.
+    #[repr(u32)]
+    enum GrabLoop {
+        /// The instant camera retrieves the grab results on an internal
+        /// thread and passes them to the registered image event handlers.
+        ProvidedByInstantCamera,
+        /// The application retrieves the grab results.
+        ProvidedByUser,
+    }
+    /// The standard configurations provided by pylon.
+    #[repr(u32)]
+    enum BuiltinConfiguration {
+        /// Trigger each frame by software, see
+        /// [InstantCamera::execute_software_trigger].
+        SoftwareTrigger,
+        /// Acquire single frames without a trigger.
+        AcquireSingleFrame,
+        /// Acquire frames continuously without a trigger. pylon registers
+        /// this configuration by default.
+        AcquireContinuous,
+    }
+    /// The events forwarded by a configuration event handler.
+    #[repr(u32)]
+    enum ConfigurationEvent {
+        Attach,
+        Attached,
+        Detach,
+        Detached,
+        Destroy,
+        Destroyed,
+        Open,
+        Opened,
+        Close,
+        Closed,
+        GrabStart,
+        GrabStarted,
+        GrabStop,
+        GrabStopped,
+        CameraDeviceRemoved,
+    }
+    /// How an event handler is registered.
+    #[repr(u32)]
+    enum RegistrationMode {
+        /// Add the handler to the already registered handlers.
+        Append,
+        /// Remove all already registered handlers before adding the handler.
+        ReplaceAll,
+    }
+    /// The ways in which a device can be opened.
+    #[repr(u32)]
+    enum AccessMode {
+        /// Access to the device's parameters.
+        Control,
+        /// Access to the device's image stream.
+        Stream,
+        /// Access to the device's events.
+        Event,
+        /// Exclusive access, no other process can open the device.
+        Exclusive,
+        /// Read-only access to a device which is controlled by another
+        /// application.
+        Monitor,
+    }
+    /// Whether a device can be opened and, if not, why.
+    #[repr(u32)]
+    enum DeviceAccessibility {
+        /// The accessibility could not be determined.
+        Unknown,
+        /// The device can be opened.
+        Ok,
+        /// The device is already opened by another application.
+        Opened,
+        /// The device is already opened exclusively by another application.
+        OpenedExclusively,
+        /// The device is not reachable, e.g. it is in a different subnet.
+        NotReachable,
+    }
 
     unsafe extern "C++" {
         include!("pylon/PylonIncludes.h");
         include!("pylon/gige/BaslerGigECamera.h");
+        include!("pylon/gige/GigETransportLayer.h");
         include!("catcher.h");
         include!("pylon-cxx-rs.h");
 
@@ -98,14 +289,25 @@ mod ffi {
         type CGrabResultPtr;
         type TimeoutHandling;
         type GrabStrategy;
+        type GrabLoop;
+        type BuiltinConfiguration;
+        type ConfigurationEvent;
+        type RegistrationMode;
+        type AccessMode;
+        type DeviceAccessibility;
         type CBooleanParameter;
         type CIntegerParameter;
         type CFloatParameter;
         type CEnumParameter;
         type CCommandParameter;
+        type CStringParameter;
 
         type MyNodeMap;
         type WaitObject;
+        type GigETransportLayerHandle;
+        type RustConfigurationEventHandler;
+        type RustImageEventHandler;
+        type RustCameraEventHandler;
 
         fn PylonInitialize();
         fn PylonTerminate(ShutDownLogging: bool);
@@ -120,11 +322,36 @@ mod ffi {
         fn tl_factory_create_device(device_info: &CDeviceInfo)
             -> Result<UniquePtr<CInstantCamera>>;
         fn tl_factory_enumerate_devices() -> Result<UniquePtr<CxxVector<CDeviceInfo>>>;
+        fn tl_factory_is_device_accessible(
+            device_info: &CDeviceInfo,
+            access_modes: u32,
+        ) -> Result<DeviceAccessibility>;
+        fn tl_factory_create_gige_transport_layer() -> Result<UniquePtr<GigETransportLayerHandle>>;
+
+        fn gige_transport_layer_issue_action_command(
+            tl: &UniquePtr<GigETransportLayerHandle>,
+            device_key: u32,
+            group_key: u32,
+            group_mask: u32,
+            broadcast_address: &str,
+        ) -> Result<()>;
+        fn gige_transport_layer_issue_scheduled_action_command(
+            tl: &UniquePtr<GigETransportLayerHandle>,
+            device_key: u32,
+            group_key: u32,
+            group_mask: u32,
+            action_time_ns: u64,
+            broadcast_address: &str,
+        ) -> Result<()>;
 
         fn instant_camera_get_device_info(
             camera: &UniquePtr<CInstantCamera>,
         ) -> UniquePtr<CDeviceInfo>;
         fn instant_camera_open(camera: &UniquePtr<CInstantCamera>) -> Result<()>;
+        fn instant_camera_open_with_access_mode(
+            camera: &UniquePtr<CInstantCamera>,
+            access_modes: u32,
+        ) -> Result<()>;
         fn instant_camera_is_open(camera: &UniquePtr<CInstantCamera>) -> Result<bool>;
         fn instant_camera_close(camera: &UniquePtr<CInstantCamera>) -> Result<()>;
         fn instant_camera_start_grabbing(camera: &UniquePtr<CInstantCamera>) -> Result<()>;
@@ -142,7 +369,24 @@ mod ffi {
             count: u32,
             grab_strategy: GrabStrategy,
         ) -> Result<()>;
+        fn instant_camera_start_grabbing_with_grab_loop(
+            camera: &UniquePtr<CInstantCamera>,
+            grab_strategy: GrabStrategy,
+            grab_loop: GrabLoop,
+        ) -> Result<()>;
+        fn instant_camera_start_grabbing_with_count_and_grab_loop(
+            camera: &UniquePtr<CInstantCamera>,
+            count: u32,
+            grab_strategy: GrabStrategy,
+            grab_loop: GrabLoop,
+        ) -> Result<()>;
         fn instant_camera_is_grabbing(camera: &UniquePtr<CInstantCamera>) -> bool;
+        fn instant_camera_is_camera_device_removed(camera: &UniquePtr<CInstantCamera>) -> bool;
+        fn instant_camera_destroy_device(camera: &UniquePtr<CInstantCamera>) -> Result<()>;
+        fn instant_camera_attach_device(
+            camera: &UniquePtr<CInstantCamera>,
+            device_info: &CDeviceInfo,
+        ) -> Result<()>;
         #[cfg(all(not(target_os = "windows"), feature = "stream"))]
         fn instant_camera_wait_object_fd(camera: &UniquePtr<CInstantCamera>) -> i32;
         #[cfg(all(target_os = "windows", feature = "stream"))]
@@ -153,6 +397,22 @@ mod ffi {
             grab_result: &mut UniquePtr<CGrabResultPtr>,
             timeout_handling: TimeoutHandling,
         ) -> Result<bool>;
+        fn instant_camera_grab_one(
+            camera: &UniquePtr<CInstantCamera>,
+            timeout_ms: u32,
+            grab_result: &mut UniquePtr<CGrabResultPtr>,
+        ) -> Result<()>;
+        fn instant_camera_can_wait_for_frame_trigger_ready(
+            camera: &UniquePtr<CInstantCamera>,
+        ) -> Result<bool>;
+        fn instant_camera_wait_for_frame_trigger_ready(
+            camera: &UniquePtr<CInstantCamera>,
+            timeout_ms: u32,
+            timeout_handling: TimeoutHandling,
+        ) -> Result<bool>;
+        fn instant_camera_execute_software_trigger(
+            camera: &UniquePtr<CInstantCamera>,
+        ) -> Result<()>;
 
         fn instant_camera_get_node_map(camera: &UniquePtr<CInstantCamera>) -> Result<&MyNodeMap>;
         fn instant_camera_get_tl_node_map(camera: &UniquePtr<CInstantCamera>)
@@ -167,6 +427,67 @@ mod ffi {
             camera: &UniquePtr<CInstantCamera>,
         ) -> Result<&MyNodeMap>;
 
+        fn new_configuration_event_handler(
+            handler: Box<ConfigurationEventHandlerBox>,
+        ) -> UniquePtr<RustConfigurationEventHandler>;
+        fn instant_camera_register_configuration_event_handler(
+            camera: &UniquePtr<CInstantCamera>,
+            handler: &UniquePtr<RustConfigurationEventHandler>,
+            mode: RegistrationMode,
+        ) -> Result<()>;
+        fn instant_camera_deregister_configuration_event_handler(
+            camera: &UniquePtr<CInstantCamera>,
+            handler: &UniquePtr<RustConfigurationEventHandler>,
+        ) -> Result<bool>;
+
+        fn instant_camera_register_builtin_configuration(
+            camera: &UniquePtr<CInstantCamera>,
+            configuration: BuiltinConfiguration,
+            mode: RegistrationMode,
+        ) -> Result<()>;
+        fn instant_camera_register_configuration(
+            camera: &UniquePtr<CInstantCamera>,
+            configuration: Box<ConfigurationBox>,
+            mode: RegistrationMode,
+        ) -> Result<()>;
+
+        fn instant_camera_set_buffer_factory(
+            camera: &UniquePtr<CInstantCamera>,
+            allocator: Box<BufferAllocatorBox>,
+        ) -> Result<()>;
+
+        fn new_image_event_handler(
+            handler: Box<ImageEventHandlerBox>,
+        ) -> UniquePtr<RustImageEventHandler>;
+        fn instant_camera_register_image_event_handler(
+            camera: &UniquePtr<CInstantCamera>,
+            handler: &UniquePtr<RustImageEventHandler>,
+            mode: RegistrationMode,
+        ) -> Result<()>;
+        fn instant_camera_deregister_image_event_handler(
+            camera: &UniquePtr<CInstantCamera>,
+            handler: &UniquePtr<RustImageEventHandler>,
+        ) -> Result<bool>;
+
+        fn new_camera_event_handler(
+            handler: Box<CameraEventHandlerBox>,
+        ) -> UniquePtr<RustCameraEventHandler>;
+        fn instant_camera_register_camera_event_handler(
+            camera: &UniquePtr<CInstantCamera>,
+            handler: &UniquePtr<RustCameraEventHandler>,
+            node_name: &str,
+        ) -> Result<()>;
+        fn instant_camera_deregister_camera_event_handler(
+            camera: &UniquePtr<CInstantCamera>,
+            handler: &UniquePtr<RustCameraEventHandler>,
+            node_name: &str,
+        ) -> Result<bool>;
+
+        fn instant_camera_ref_get_device_info(camera: &CInstantCamera) -> UniquePtr<CDeviceInfo>;
+        fn instant_camera_ref_get_node_map(camera: &CInstantCamera) -> Result<&MyNodeMap>;
+        fn instant_camera_ref_is_grabbing(camera: &CInstantCamera) -> bool;
+        fn instant_camera_ref_is_camera_device_removed(camera: &CInstantCamera) -> bool;
+
         fn node_map_load(node_map: &MyNodeMap, filename: String, validate: bool) -> Result<()>;
         fn node_map_save(node_map: &MyNodeMap, filename: String) -> Result<()>;
         fn node_map_load_from_string(
@@ -176,6 +497,7 @@ mod ffi {
         ) -> Result<()>;
         fn node_map_save_to_string(node_map: &MyNodeMap) -> Result<String>;
 
+        fn node_map_has_node(node_map: &MyNodeMap, name: &str) -> bool;
         fn node_map_get_boolean_parameter(
             node_map: &MyNodeMap,
             name: &str,
@@ -197,6 +519,10 @@ mod ffi {
             node_map: &MyNodeMap,
             name: &str,
         ) -> Result<UniquePtr<CCommandParameter>>;
+        fn node_map_get_string_parameter(
+            node_map: &MyNodeMap,
+            name: &str,
+        ) -> Result<UniquePtr<CStringParameter>>;
 
         fn boolean_node_get_value(boolean_node: &UniquePtr<CBooleanParameter>) -> Result<bool>;
         fn boolean_node_set_value(
@@ -226,7 +552,11 @@ mod ffi {
 
         fn command_node_execute(node: &UniquePtr<CCommandParameter>, verify: bool) -> Result<()>;
 
+        fn string_node_get_value(node: &UniquePtr<CStringParameter>) -> Result<String>;
+        fn string_node_set_value(node: &UniquePtr<CStringParameter>, value: &str) -> Result<()>;
+
         fn new_grab_result_ptr() -> Result<UniquePtr<CGrabResultPtr>>;
+        fn grab_result_clone(grab_result: &UniquePtr<CGrabResultPtr>) -> UniquePtr<CGrabResultPtr>;
         fn grab_result_grab_succeeded(grab_result: &UniquePtr<CGrabResultPtr>) -> Result<bool>;
         fn grab_result_error_description(grab_result: &UniquePtr<CGrabResultPtr>)
             -> Result<String>;
@@ -241,9 +571,13 @@ mod ffi {
         fn grab_result_payload_size(grab_result: &UniquePtr<CGrabResultPtr>) -> Result<u32>;
         fn grab_result_buffer_size(grab_result: &UniquePtr<CGrabResultPtr>) -> Result<u32>;
         fn grab_result_block_id(grab_result: &UniquePtr<CGrabResultPtr>) -> Result<u64>;
+        fn grab_result_buffer_context(grab_result: &UniquePtr<CGrabResultPtr>) -> Result<isize>;
         fn grab_result_time_stamp(grab_result: &UniquePtr<CGrabResultPtr>) -> Result<u64>;
         fn grab_result_stride(grab_result: &UniquePtr<CGrabResultPtr>) -> Result<usize>;
         fn grab_result_image_size(grab_result: &UniquePtr<CGrabResultPtr>) -> Result<u32>;
+        fn grab_result_number_of_skipped_images(
+            grab_result: &UniquePtr<CGrabResultPtr>,
+        ) -> Result<usize>;
         fn grab_result_get_chunk_data_node_map(
             grab_result: &UniquePtr<CGrabResultPtr>,
         ) -> Result<&MyNodeMap>;
@@ -257,32 +591,110 @@ mod ffi {
             name: &str,
         ) -> Result<String>;
         fn device_info_get_model_name(device_info: &UniquePtr<CDeviceInfo>) -> Result<String>;
+        fn device_info_get_serial_number(device_info: &UniquePtr<CDeviceInfo>) -> Result<String>;
+        fn device_info_get_full_name(device_info: &UniquePtr<CDeviceInfo>) -> Result<String>;
+        fn device_info_get_device_class(device_info: &UniquePtr<CDeviceInfo>) -> Result<String>;
         #[cfg(all(target_os = "windows", feature = "stream"))]
         fn wait_object_wait(wait_object: &UniquePtr<WaitObject>, timeout: u64) -> Result<bool>;
     }
+
+    extern "Rust" {
+        type ConfigurationEventHandlerBox;
+        type ConfigurationBox;
+        type BufferAllocatorBox;
+        type ImageEventHandlerBox;
+        type CameraEventHandlerBox;
+
+        fn configuration_event_handler_on_event(
+            handler: &mut ConfigurationEventHandlerBox,
+            camera: &CInstantCamera,
+            event: ConfigurationEvent,
+        );
+        fn configuration_event_handler_on_grab_error(
+            handler: &mut ConfigurationEventHandlerBox,
+            camera: &CInstantCamera,
+            message: &str,
+        );
+        fn buffer_allocator_allocate(
+            allocator: &BufferAllocatorBox,
+            size: usize,
+            context: &mut isize,
+        ) -> Result<usize>;
+        fn buffer_allocator_free(allocator: &BufferAllocatorBox, address: usize, context: isize);
+        fn configuration_on_opened(
+            configuration: &mut ConfigurationBox,
+            camera: &CInstantCamera,
+        ) -> Result<()>;
+        fn image_event_handler_on_image_grabbed(
+            handler: &mut ImageEventHandlerBox,
+            camera: &CInstantCamera,
+            grab_result: UniquePtr<CGrabResultPtr>,
+        );
+        fn camera_event_handler_on_camera_event(
+            handler: &mut CameraEventHandlerBox,
+            camera: &CInstantCamera,
+        );
+    }
 }
+pub use ffi::AccessMode;
+pub use ffi::BuiltinConfiguration;
+pub use ffi::DeviceAccessibility;
+pub use ffi::GrabLoop;
 pub use ffi::GrabStrategy;
+pub use ffi::RegistrationMode;
 pub use ffi::TimeoutHandling;
 
 pub struct Pylon {}
 
+/// The state of the pylon runtime, which is initialized while any [Pylon]
+/// exists.
+pub(crate) struct Runtime {
+    pub(crate) handles: usize,
+    /// The number of emulated cameras the runtime was initialized with.
+    pub(crate) emulated_devices: u32,
+}
+
+static RUNTIME: Mutex<Runtime> = Mutex::new(Runtime {
+    handles: 0,
+    emulated_devices: 0,
+});
+
+pub(crate) fn lock_runtime() -> MutexGuard<'static, Runtime> {
+    RUNTIME.lock().unwrap_or_else(|e| e.into_inner())
+}
+
 impl Pylon {
     pub fn new() -> Self {
+        Self::initialize(&mut lock_runtime())
+    }
+
+    /// Another handle which keeps the runtime initialized, e.g. on a thread
+    /// which outlives the borrow of `self`.
+    pub(crate) fn retain(&self) -> Self {
+        Self::initialize(&mut lock_runtime())
+    }
+
+    pub(crate) fn initialize(runtime: &mut Runtime) -> Self {
+        if runtime.handles == 0 {
+            runtime.emulated_devices = emulation::emulated_devices_from_env();
+        }
         ffi::PylonInitialize();
+        runtime.handles += 1;
         Self {}
     }
 }
 
 impl Default for Pylon {
     fn default() -> Self {
-        ffi::PylonInitialize();
-        Self {}
+        Self::new()
     }
 }
 
 impl Drop for Pylon {
     fn drop(&mut self) {
+        let mut runtime = lock_runtime();
         ffi::PylonTerminate(true);
+        runtime.handles -= 1;
     }
 }
 
@@ -351,6 +763,145 @@ impl<'a> TlFactory<'a> {
             })
             .collect())
     }
+    /// Check whether the device can be opened with the default access modes
+    /// (control, stream and event).
+    pub fn is_device_accessible(
+        &self,
+        device_info: &DeviceInfo,
+    ) -> PylonResult<DeviceAccessibility> {
+        self.is_device_accessible_with_access_mode(
+            device_info,
+            &[AccessMode::Control, AccessMode::Stream, AccessMode::Event],
+        )
+    }
+    /// Check whether the device can be opened with the given access modes.
+    ///
+    /// [AccessMode::Monitor] is not supported, pylon only checks the access
+    /// modes of the device itself.
+    pub fn is_device_accessible_with_access_mode(
+        &self,
+        device_info: &DeviceInfo,
+        access_modes: &[AccessMode],
+    ) -> PylonResult<DeviceAccessibility> {
+        if access_modes.contains(&AccessMode::Monitor) {
+            return Err(PylonError::with_kind(
+                ErrorKind::Unsupported,
+                "The accessibility cannot be checked for the monitor access mode",
+            ));
+        }
+        let access_modes = access_mode_bits(access_modes)?;
+        ffi::tl_factory_is_device_accessible(&device_info.inner, access_modes).into_rust()
+    }
+    /// Create the GigE transport layer, e.g. to issue action commands.
+    ///
+    /// This fails if pylon was installed without GigE support.
+    pub fn create_gige_transport_layer(&self) -> PylonResult<GigETransportLayer<'a>> {
+        let inner = ffi::tl_factory_create_gige_transport_layer()?;
+        Ok(GigETransportLayer {
+            inner,
+            _lib: self.lib,
+        })
+    }
+}
+
+/// The keys and mask of a GigE action command.
+///
+/// A camera executes an action command if the device key and group key match
+/// its `ActionDeviceKey` and `ActionGroupKey` and the bitwise AND of the group
+/// mask and its `ActionGroupMask` is non-zero.
+///
+/// Action commands are only supported by GigE cameras, through pylon's GigE
+/// transport layer on Windows and Linux. USB and CoaXPress cameras cannot
+/// receive them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActionCommand {
+    pub device_key: u32,
+    pub group_key: u32,
+    pub group_mask: u32,
+}
+
+impl ActionCommand {
+    /// A command for all cameras with the given keys. The group mask selects
+    /// all groups.
+    pub fn new(device_key: u32, group_key: u32) -> Self {
+        Self {
+            device_key,
+            group_key,
+            group_mask: 0xffff_ffff,
+        }
+    }
+
+    pub fn group_mask(self, group_mask: u32) -> Self {
+        Self { group_mask, ..self }
+    }
+
+    /// Check that the command can be executed by a camera and that
+    /// `broadcast_address` is an IPv4 address.
+    pub fn validate(&self, broadcast_address: &str) -> PylonResult<()> {
+        if self.group_mask == 0 {
+            return Err(PylonError::new(
+                "An action command with group mask 0 is not executed by any camera",
+            ));
+        }
+        broadcast_address
+            .parse::<std::net::Ipv4Addr>()
+            .map_err(|_| {
+                PylonError::new(format!(
+                    "Invalid broadcast address for action command: {}",
+                    broadcast_address
+                ))
+            })?;
+        Ok(())
+    }
+}
+
+/// Wrap the `IGigETransportLayer` type.
+pub struct GigETransportLayer<'a> {
+    inner: cxx::UniquePtr<ffi::GigETransportLayerHandle>,
+
+    /// A reference to the Pylon library. This should be the last field in the
+    /// struct so that `self._lib` is dropped after `self.inner`.
+    _lib: &'a Pylon,
+}
+
+impl GigETransportLayer<'_> {
+    /// Issue an action command to all cameras reachable via `broadcast_address`
+    /// (e.g. `"255.255.255.255"`).
+    pub fn issue_action_command(
+        &self,
+        command: &ActionCommand,
+        broadcast_address: &str,
+    ) -> PylonResult<()> {
+        command.validate(broadcast_address)?;
+        ffi::gige_transport_layer_issue_action_command(
+            &self.inner,
+            command.device_key,
+            command.group_key,
+            command.group_mask,
+            broadcast_address,
+        )
+        .into_rust()
+    }
+
+    /// Issue an action command which the cameras execute when their
+    /// (synchronized) clock reaches `action_time_ns`.
+    pub fn issue_scheduled_action_command(
+        &self,
+        command: &ActionCommand,
+        action_time_ns: u64,
+        broadcast_address: &str,
+    ) -> PylonResult<()> {
+        command.validate(broadcast_address)?;
+        ffi::gige_transport_layer_issue_scheduled_action_command(
+            &self.inner,
+            command.device_key,
+            command.group_key,
+            command.group_mask,
+            action_time_ns,
+            broadcast_address,
+        )
+        .into_rust()
+    }
 }
 
 #[cfg(all(target_os = "windows", feature = "stream"))]
@@ -368,17 +919,62 @@ unsafe impl Send for WaitObject {}
 
 /// Wrap the CInstantCamera type
 pub struct InstantCamera<'a> {
+    /// Dropped before `inner`, so that the wait object fd is deregistered
+    /// while pylon keeps it open.
+    state: CameraState,
     inner: cxx::UniquePtr<ffi::CInstantCamera>,
-    #[cfg(all(not(target_os = "windows"), feature = "stream"))]
-    fd: RefCell<Option<tokio::io::unix::AsyncFd<std::os::unix::io::RawFd>>>,
-    #[cfg(all(target_os = "windows", feature = "stream"))]
-    wait_thread: RefCell<Option<JoinHandle<()>>>,
 
     /// A reference to the Pylon library. This should be the last field in the
     /// struct so that `self._lib` is dropped after `self.inner`.
     _lib: &'a Pylon,
 }
 
+/// The state this crate keeps for an [InstantCamera]. It stays with the
+/// pylon camera object when the camera is detached from its [Pylon]
+/// reference, see [DetachedCamera].
+#[derive(Default)]
+struct CameraState {
+    #[cfg(all(not(target_os = "windows"), feature = "stream"))]
+    fd: RefCell<Option<stream_unix::AsyncWaitFd>>,
+    #[cfg(all(target_os = "windows", feature = "stream"))]
+    wait_thread: RefCell<Option<JoinHandle<()>>>,
+    /// The options of the last `start_grabbing` call, used to restart grabbing
+    /// after reconnecting.
+    grab_options: RefCell<Option<GrabOptions>>,
+    reconnect_policy: RefCell<Option<ReconnectPolicy>>,
+    /// The node values restored after reconnecting.
+    node_map_snapshot: RefCell<Option<String>>,
+    grab_statistics: RefCell<Option<Arc<Mutex<GrabStatistics>>>>,
+    stream_statistics_baseline: RefCell<Option<StreamStatistics>>,
+}
+
+/// An [InstantCamera] without its reference to the pylon runtime, so that it
+/// can be moved to a thread which keeps the runtime initialized itself.
+struct DetachedCamera {
+    state: CameraState,
+    inner: cxx::UniquePtr<ffi::CInstantCamera>,
+}
+
+// Like `InstantCamera`, the camera can be moved between threads.
+unsafe impl Send for DetachedCamera {}
+
+impl DetachedCamera {
+    fn new(camera: InstantCamera<'_>) -> Self {
+        Self {
+            state: camera.state,
+            inner: camera.inner,
+        }
+    }
+
+    fn attach(self, lib: &Pylon) -> InstantCamera<'_> {
+        InstantCamera {
+            state: self.state,
+            inner: self.inner,
+            _lib: lib,
+        }
+    }
+}
+
 /// Wrap the `GenApi::INodeMap` type.
 ///
 /// This provides access to the various nodes (boolean, integer, float, enum,
@@ -413,6 +1009,10 @@ impl<'map, 'parent: 'map> NodeMap<'map, 'parent> {
     pub fn save_to_string(&self) -> PylonResult<String> {
         ffi::node_map_save_to_string(self.inner).into_rust()
     }
+    /// Return whether a node with the given name exists in the nodemap.
+    pub fn has_node(&self, name: &str) -> bool {
+        ffi::node_map_has_node(self.inner, name)
+    }
     pub fn boolean_node(&self, name: &str) -> PylonResult<BooleanNode> {
         let name = name.to_string();
         let inner = ffi::node_map_get_boolean_parameter(self.inner, &name)?;
@@ -438,13 +1038,24 @@ impl<'map, 'parent: 'map> NodeMap<'map, 'parent> {
         let inner = ffi::node_map_get_command_parameter(self.inner, &name)?;
         Ok(CommandNode { name, inner })
     }
+    pub fn string_node(&self, name: &str) -> PylonResult<StringNode> {
+        let name = name.to_string();
+        let inner = ffi::node_map_get_string_parameter(self.inner, &name)?;
+        Ok(StringNode { name, inner })
+    }
 }
 
 /// Options passed to `start_grabbing`.
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct GrabOptions {
     count: Option<u32>,
     strategy: Option<GrabStrategy>,
+    grab_loop: Option<GrabLoop>,
+    max_num_buffer: Option<i64>,
+    max_num_queued_buffer: Option<i64>,
+    output_queue_size: Option<i64>,
+    grab_loop_thread_priority: Option<i64>,
+    chunk_node_maps_enable: Option<bool>,
 }
 
 impl GrabOptions {
@@ -461,6 +1072,72 @@ impl GrabOptions {
             ..self
         }
     }
+
+    /// Use [GrabLoop::ProvidedByInstantCamera] to have the registered image
+    /// event handlers called from pylon's grab loop thread.
+    pub fn grab_loop(self, grab_loop: GrabLoop) -> GrabOptions {
+        Self {
+            grab_loop: Some(grab_loop),
+            ..self
+        }
+    }
+
+    /// The number of buffers allocated for grabbing (`MaxNumBuffer`).
+    pub fn max_num_buffer(self, max_num_buffer: i64) -> GrabOptions {
+        Self {
+            max_num_buffer: Some(max_num_buffer),
+            ..self
+        }
+    }
+
+    /// The maximum number of buffers queued in the stream grabber
+    /// (`MaxNumQueuedBuffer`).
+    pub fn max_num_queued_buffer(self, max_num_queued_buffer: i64) -> GrabOptions {
+        Self {
+            max_num_queued_buffer: Some(max_num_queued_buffer),
+            ..self
+        }
+    }
+
+    /// The size of the output queue for [GrabStrategy::LatestImages]
+    /// (`OutputQueueSize`).
+    pub fn output_queue_size(self, output_queue_size: i64) -> GrabOptions {
+        Self {
+            output_queue_size: Some(output_queue_size),
+            ..self
+        }
+    }
+
+    /// Override the priority of pylon's grab loop thread
+    /// (`GrabLoopThreadPriority`).
+    pub fn grab_loop_thread_priority(self, priority: i64) -> GrabOptions {
+        Self {
+            grab_loop_thread_priority: Some(priority),
+            ..self
+        }
+    }
+
+    /// Whether chunk data is parsed into the chunk node maps of the grab
+    /// results (`ChunkNodeMapsEnable`).
+    pub fn chunk_node_maps_enable(self, enable: bool) -> GrabOptions {
+        Self {
+            chunk_node_maps_enable: Some(enable),
+            ..self
+        }
+    }
+}
+
+/// Set an integer node after checking `value` against its range.
+fn set_integer_in_range(node_map: &NodeMap, name: &str, value: i64) -> PylonResult<()> {
+    let mut node = node_map.integer_node(name)?;
+    let (min, max) = (node.min()?, node.max()?);
+    if value < min || value > max {
+        return Err(PylonError::new(format!(
+            "{} must be between {} and {}, got {}",
+            name, min, max, value
+        )));
+    }
+    node.set_value(value)
 }
 
 pub struct BooleanNode {
@@ -578,17 +1255,31 @@ impl CommandNode {
     }
 }
 
+pub struct StringNode {
+    name: String,
+    inner: cxx::UniquePtr<ffi::CStringParameter>,
+}
+
+impl StringNode {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn value(&self) -> PylonResult<String> {
+        ffi::string_node_get_value(&self.inner).into_rust()
+    }
+    pub fn set_value(&mut self, value: &str) -> PylonResult<()> {
+        ffi::string_node_set_value(&self.inner, value).into_rust()
+    }
+}
+
 unsafe impl Send for InstantCamera<'_> {}
 
 impl<'a> InstantCamera<'a> {
     pub fn new(lib: &'a Pylon, inner: cxx::UniquePtr<ffi::CInstantCamera>) -> Self {
         InstantCamera {
-            _lib: lib,
+            state: CameraState::default(),
             inner,
-            #[cfg(all(not(target_os = "windows"), feature = "stream"))]
-            fd: RefCell::new(None),
-            #[cfg(all(target_os = "windows", feature = "stream"))]
-            wait_thread: RefCell::new(None),
+            _lib: lib,
         }
     }
 
@@ -599,7 +1290,20 @@ impl<'a> InstantCamera<'a> {
     }
 
     pub fn open(&self) -> PylonResult<()> {
-        ffi::instant_camera_open(&self.inner).into_rust()
+        ffi::instant_camera_open(&self.inner).into_rust()?;
+        self.save_node_map_snapshot()
+    }
+
+    /// Open the camera with the given access modes.
+    ///
+    /// [AccessMode::Monitor] cannot be combined with other access modes. It
+    /// allows reading parameters of a camera which is controlled by another
+    /// application. Registered configurations are applied and configuration
+    /// event handlers are called like in [InstantCamera::open].
+    pub fn open_with_access_mode(&self, access_modes: &[AccessMode]) -> PylonResult<()> {
+        let access_modes = access_mode_bits(access_modes)?;
+        ffi::instant_camera_open_with_access_mode(&self.inner, access_modes).into_rust()?;
+        self.save_node_map_snapshot()
     }
 
     pub fn is_open(&self) -> PylonResult<bool> {
@@ -610,19 +1314,53 @@ impl<'a> InstantCamera<'a> {
         ffi::instant_camera_close(&self.inner).into_rust()
     }
 
-    pub fn start_grabbing(&self, options: &GrabOptions) -> PylonResult<()> {
-        // we assign the waitobject fd here for using it in the stream to poll for progress
-        #[cfg(all(not(target_os = "windows"), feature = "stream"))]
-        {
-            if tokio::runtime::Handle::try_current().is_ok() {
-                self.fd.replace(Some(tokio::io::unix::AsyncFd::new(
-                    self.get_grab_result_fd()?,
-                )?));
-            }
+    /// Apply the buffer and thread options to the instant camera node map.
+    fn apply_grab_options(&self, options: &GrabOptions) -> PylonResult<()> {
+        let node_map = self.instant_camera_node_map()?;
+        if let Some(value) = options.max_num_buffer {
+            set_integer_in_range(&node_map, "MaxNumBuffer", value)?;
         }
+        if let Some(value) = options.max_num_queued_buffer {
+            set_integer_in_range(&node_map, "MaxNumQueuedBuffer", value)?;
+        }
+        if let Some(value) = options.output_queue_size {
+            set_integer_in_range(&node_map, "OutputQueueSize", value)?;
+        }
+        if let Some(value) = options.grab_loop_thread_priority {
+            node_map
+                .boolean_node("GrabLoopThreadPriorityOverride")?
+                .set_value(true)?;
+            set_integer_in_range(&node_map, "GrabLoopThreadPriority", value)?;
+        }
+        if let Some(value) = options.chunk_node_maps_enable {
+            node_map
+                .boolean_node("ChunkNodeMapsEnable")?
+                .set_value(value)?;
+        }
+        Ok(())
+    }
+
+    pub fn start_grabbing(&self, options: &GrabOptions) -> PylonResult<()> {
+        self.apply_grab_options(options)?;
+        self.reset_async_wait();
 
-        match (options.count, options.strategy) {
-            (Some(count), Some(strategy)) => {
+        match (options.count, options.strategy, options.grab_loop) {
+            (Some(count), strategy, Some(grab_loop)) => {
+                ffi::instant_camera_start_grabbing_with_count_and_grab_loop(
+                    &self.inner,
+                    count,
+                    strategy.unwrap_or(GrabStrategy::OneByOne),
+                    grab_loop,
+                )
+                .into_rust()
+            }
+            (None, strategy, Some(grab_loop)) => ffi::instant_camera_start_grabbing_with_grab_loop(
+                &self.inner,
+                strategy.unwrap_or(GrabStrategy::OneByOne),
+                grab_loop,
+            )
+            .into_rust(),
+            (Some(count), Some(strategy), None) => {
                 ffi::instant_camera_start_grabbing_with_count_and_strategy(
                     &self.inner,
                     count,
@@ -630,24 +1368,33 @@ impl<'a> InstantCamera<'a> {
                 )
                 .into_rust()
             }
-            (Some(count), None) => {
+            (Some(count), None, None) => {
                 ffi::instant_camera_start_grabbing_with_count(&self.inner, count).into_rust()
             }
-            (None, Some(strategy)) => {
+            (None, Some(strategy), None) => {
                 ffi::instant_camera_start_grabbing_with_strategy(&self.inner, strategy).into_rust()
             }
-            (None, None) => ffi::instant_camera_start_grabbing(&self.inner).into_rust(),
-        }
+            (None, None, None) => ffi::instant_camera_start_grabbing(&self.inner).into_rust(),
+        }?;
+        self.state.grab_options.replace(Some(options.clone()));
+        Ok(())
     }
 
     pub fn stop_grabbing(&self) -> PylonResult<()> {
+        // The wait object fd is deregistered from the async runtime first.
+        self.reset_async_wait();
         ffi::instant_camera_stop_grabbing(&self.inner).into_rust()?;
-        #[cfg(all(not(target_os = "windows"), feature = "stream"))]
-        self.fd.replace(None);
+        self.state.grab_options.replace(None);
+        Ok(())
+    }
 
+    /// Deregister the grab result wait object from the async runtime. It is
+    /// registered again when the camera is polled while grabbing.
+    pub(crate) fn reset_async_wait(&self) {
+        #[cfg(all(not(target_os = "windows"), feature = "stream"))]
+        self.state.fd.replace(None);
         #[cfg(all(target_os = "windows", feature = "stream"))]
-        self.wait_thread.replace(None);
-        Ok(())
+        self.state.wait_thread.replace(None);
     }
 
     pub fn is_grabbing(&self) -> bool {
@@ -655,19 +1402,82 @@ impl<'a> InstantCamera<'a> {
         ffi::instant_camera_is_grabbing(&self.inner)
     }
 
+    /// Return whether the camera device has been removed, e.g. because the
+    /// cable was unplugged or the GigE heartbeat expired.
+    pub fn is_camera_device_removed(&self) -> bool {
+        // According to InstantCamera.h, `IsCameraDeviceRemoved()` does not throw C++ exceptions.
+        ffi::instant_camera_is_camera_device_removed(&self.inner)
+    }
+
+    /// Retrieve a grab result.
+    ///
+    /// If no result could be retrieved because the camera device has been
+    /// removed, an error of kind [ErrorKind::DeviceRemoved] is returned, see
+    /// [InstantCamera::reconnect].
     pub fn retrieve_result(
         &self,
         timeout_ms: u32,
         grab_result: &mut GrabResult,
         timeout_handling: TimeoutHandling,
     ) -> PylonResult<bool> {
-        ffi::instant_camera_retrieve_result(
+        let result = ffi::instant_camera_retrieve_result(
             &self.inner,
             timeout_ms,
             &mut grab_result.inner,
             timeout_handling,
         )
-        .into_rust()
+        .into_rust();
+        match result {
+            Ok(true) => self.record_grab_statistics(grab_result),
+            _ if self.is_camera_device_removed() => {
+                return Err(PylonError::with_kind(
+                    ErrorKind::DeviceRemoved,
+                    "The camera device has been removed",
+                ))
+            }
+            _ => {}
+        }
+        result
+    }
+
+    /// Grab a single image.
+    ///
+    /// This opens the camera if needed, grabs one image and stops grabbing
+    /// again. The camera must not be grabbing already. An error is returned
+    /// if no image arrives within `timeout_ms` or if the grab failed, in which
+    /// case the error contains pylon's error code and description.
+    pub fn grab_one(&self, timeout_ms: u32) -> PylonResult<GrabResult> {
+        let mut grab_result = GrabResult::new()?;
+        ffi::instant_camera_grab_one(&self.inner, timeout_ms, &mut grab_result.inner)?;
+        self.record_grab_statistics(&grab_result);
+        grab_result.check_succeeded()?;
+        Ok(grab_result)
+    }
+
+    /// Configure the camera to start a frame when it receives `command`.
+    ///
+    /// This sets `ActionDeviceKey`, `ActionGroupKey` and `ActionGroupMask` and
+    /// switches the frame start trigger to `TriggerSource=Action1`.
+    pub fn configure_action_trigger(&self, command: &ActionCommand) -> PylonResult<()> {
+        let node_map = self.node_map()?;
+        node_map
+            .enum_node("TriggerSelector")?
+            .set_value("FrameStart")?;
+        node_map.enum_node("TriggerMode")?.set_value("On")?;
+        node_map.enum_node("TriggerSource")?.set_value("Action1")?;
+        if node_map.has_node("ActionSelector") {
+            node_map.integer_node("ActionSelector")?.set_value(1)?;
+        }
+        node_map
+            .integer_node("ActionDeviceKey")?
+            .set_value(command.device_key.into())?;
+        node_map
+            .integer_node("ActionGroupKey")?
+            .set_value(command.group_key.into())?;
+        node_map
+            .integer_node("ActionGroupMask")?
+            .set_value(command.group_mask.into())?;
+        Ok(())
     }
 
     #[cfg(all(not(target_os = "windows"), feature = "stream"))]
@@ -716,12 +1526,68 @@ impl<'a> InstantCamera<'a> {
     }
 }
 
+/// A borrowed camera, passed to event handlers.
+///
+/// This gives access to the camera which called the event handler. It is only
+/// valid during the call.
+pub struct InstantCameraRef<'c> {
+    inner: &'c ffi::CInstantCamera,
+}
+
+impl<'c> InstantCameraRef<'c> {
+    pub fn device_info(&self) -> DeviceInfo {
+        DeviceInfo {
+            inner: ffi::instant_camera_ref_get_device_info(self.inner),
+        }
+    }
+
+    pub fn node_map<'map>(&self) -> PylonResult<NodeMap<'map, 'c>>
+    where
+        'c: 'map,
+    {
+        Ok(NodeMap {
+            inner: ffi::instant_camera_ref_get_node_map(self.inner)?,
+            parent: std::marker::PhantomData,
+        })
+    }
+
+    pub fn is_grabbing(&self) -> bool {
+        ffi::instant_camera_ref_is_grabbing(self.inner)
+    }
+
+    pub fn is_camera_device_removed(&self) -> bool {
+        ffi::instant_camera_ref_is_camera_device_removed(self.inner)
+    }
+}
+
+/// The result of a grab, holding a reference to a grab buffer.
+///
+/// Cloning a grab result is cheap and shares the buffer with the original,
+/// like copying a `CGrabResultPtr` in C++. pylon requeues the buffer for
+/// grabbing only after all clones have been dropped or have been passed to
+/// [InstantCamera::retrieve_result] again, so the data returned by
+/// [GrabResult::buffer] never changes while a clone is alive. Keeping many
+/// grab results alive makes the camera run out of buffers, see
+/// [GrabOptions::max_num_buffer].
 pub struct GrabResult {
     inner: cxx::UniquePtr<ffi::CGrabResultPtr>,
 }
 
 unsafe impl Send for GrabResult {}
 
+// The reference count of `CGrabResultPtr` is thread-safe, all accessors only
+// read the grab result data and access to the chunk node map is serialized by
+// the node map's lock.
+unsafe impl Sync for GrabResult {}
+
+impl Clone for GrabResult {
+    fn clone(&self) -> Self {
+        Self {
+            inner: ffi::grab_result_clone(&self.inner),
+        }
+    }
+}
+
 impl GrabResult {
     pub fn new() -> PylonResult<Self> {
         Ok(Self {
@@ -733,6 +1599,23 @@ impl GrabResult {
         ffi::grab_result_grab_succeeded(&self.inner).into_rust()
     }
 
+    /// Return an error with pylon's error code and description if the grab
+    /// failed.
+    fn check_succeeded(&self) -> PylonResult<()> {
+        if self.grab_succeeded()? {
+            Ok(())
+        } else {
+            Err(PylonError::with_kind(
+                ErrorKind::GrabFailed,
+                format!(
+                    "Grab failed with error code {:#x}: {}",
+                    self.error_code()?,
+                    self.error_description()?
+                ),
+            ))
+        }
+    }
+
     pub fn error_description(&self) -> PylonResult<String> {
         ffi::grab_result_error_description(&self.inner).into_rust()
     }
@@ -781,6 +1664,12 @@ impl GrabResult {
         ffi::grab_result_block_id(&self.inner).into_rust()
     }
 
+    /// The context the [BufferAllocator] returned for the buffer of this
+    /// grab result.
+    pub fn buffer_context(&self) -> PylonResult<isize> {
+        ffi::grab_result_buffer_context(&self.inner).into_rust()
+    }
+
     pub fn time_stamp(&self) -> PylonResult<u64> {
         ffi::grab_result_time_stamp(&self.inner).into_rust()
     }
@@ -794,6 +1683,12 @@ impl GrabResult {
         ffi::grab_result_image_size(&self.inner).into_rust()
     }
 
+    /// The number of images which were skipped before this one because the
+    /// grab strategy only keeps the latest images.
+    pub fn number_of_skipped_images(&self) -> PylonResult<usize> {
+        ffi::grab_result_number_of_skipped_images(&self.inner).into_rust()
+    }
+
     pub fn chunk_data_node_map<'map>(&self) -> PylonResult<NodeMap<'map, '_>> {
         Ok(NodeMap {
             inner: ffi::grab_result_get_chunk_data_node_map(&self.inner)?,
@@ -848,6 +1743,16 @@ impl DeviceInfo {
     pub fn model_name(&self) -> PylonResult<String> {
         ffi::device_info_get_model_name(&self.inner).into_rust()
     }
+    pub fn serial_number(&self) -> PylonResult<String> {
+        ffi::device_info_get_serial_number(&self.inner).into_rust()
+    }
+    /// The full name uniquely identifies a device.
+    pub fn full_name(&self) -> PylonResult<String> {
+        ffi::device_info_get_full_name(&self.inner).into_rust()
+    }
+    pub fn device_class(&self) -> PylonResult<String> {
+        ffi::device_info_get_device_class(&self.inner).into_rust()
+    }
 }
 
 pub struct DeviceInfo {
@@ -864,13 +1769,42 @@ impl Clone for DeviceInfo {
 
 unsafe impl Send for DeviceInfo {}
 
+impl std::fmt::Debug for DeviceInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("DeviceInfo")
+            .field("full_name", &self.full_name().ok())
+            .finish()
+    }
+}
+
+/// Device infos are equal if they describe the same device.
+impl PartialEq for DeviceInfo {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.full_name(), other.full_name()) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+fn access_mode_bits(access_modes: &[AccessMode]) -> PylonResult<u32> {
+    let bits = access_modes
+        .iter()
+        .fold(0, |bits, mode| bits | (1 << mode.repr));
+    if bits == 0 {
+        return Err(PylonError::new("No access mode given"));
+    }
+    if bits & (1 << AccessMode::Monitor.repr) != 0 && bits != 1 << AccessMode::Monitor.repr {
+        return Err(PylonError::new(
+            "Monitor access mode cannot be combined with other access modes",
+        ));
+    }
+    Ok(bits)
+}
+
 fn path_to_string<P: AsRef<std::path::Path>>(path: P) -> PylonResult<String> {
     match path.as_ref().to_str() {
         Some(filename) => Ok(filename.into()),
-        None => Err(PylonError {
-            msg: "Cannot convert path to UTF-8".to_string(),
-            #[cfg(feature = "backtrace")]
-            backtrace: Backtrace::capture(),
-        }),
+        None => Err(PylonError::new("Cannot convert path to UTF-8")),
     }
 }
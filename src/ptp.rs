@@ -0,0 +1,237 @@
+use std::time::{Duration, Instant};
+
+use crate::{ErrorKind, InstantCamera, NodeMap, PylonError, PylonResult};
+
+/// How often [wait_for_ptp_lock] reads the status of the cameras.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The PTP node names of a camera. Newer cameras use the standard feature
+/// naming convention, older GigE cameras the `GevIEEE1588` names.
+struct PtpNodes {
+    enable: &'static str,
+    latch: &'static str,
+    status: &'static str,
+    servo_status: Option<&'static str>,
+    clock_id: &'static str,
+    parent_clock_id: &'static str,
+    offset_from_master: &'static str,
+}
+
+const PTP_NODES: PtpNodes = PtpNodes {
+    enable: "PtpEnable",
+    latch: "PtpDataSetLatch",
+    status: "PtpStatus",
+    servo_status: Some("PtpServoStatus"),
+    clock_id: "PtpClockID",
+    parent_clock_id: "PtpParentClockID",
+    offset_from_master: "PtpOffsetFromMaster",
+};
+
+const GEV_IEEE1588_NODES: PtpNodes = PtpNodes {
+    enable: "GevIEEE1588",
+    latch: "GevIEEE1588DataSetLatch",
+    status: "GevIEEE1588StatusLatched",
+    servo_status: None,
+    clock_id: "GevIEEE1588ClockId",
+    parent_clock_id: "GevIEEE1588ParentClockId",
+    offset_from_master: "GevIEEE1588OffsetFromMaster",
+};
+
+impl PtpNodes {
+    fn of(node_map: &NodeMap) -> PylonResult<&'static Self> {
+        if node_map.has_node(PTP_NODES.enable) {
+            Ok(&PTP_NODES)
+        } else if node_map.has_node(GEV_IEEE1588_NODES.enable) {
+            Ok(&GEV_IEEE1588_NODES)
+        } else {
+            Err(PylonError::with_kind(
+                ErrorKind::Unsupported,
+                "The camera does not support PTP",
+            ))
+        }
+    }
+}
+
+/// The state of the PTP clock of a camera.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PtpState {
+    Initializing,
+    Faulty,
+    Disabled,
+    Listening,
+    PreMaster,
+    Master,
+    Passive,
+    Uncalibrated,
+    Slave,
+    /// A state this crate does not know, by its enum entry name.
+    Other(String),
+}
+
+impl PtpState {
+    fn from_entry(entry: &str) -> Self {
+        match entry {
+            "Initializing" => Self::Initializing,
+            "Faulty" => Self::Faulty,
+            "Disabled" => Self::Disabled,
+            "Listening" => Self::Listening,
+            "PreMaster" => Self::PreMaster,
+            "Master" => Self::Master,
+            "Passive" => Self::Passive,
+            "Uncalibrated" => Self::Uncalibrated,
+            "Slave" => Self::Slave,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+/// The PTP data set of a camera, latched at one point in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PtpStatus {
+    pub state: PtpState,
+    /// Whether the clock servo is locked, if the camera reports it.
+    pub servo_locked: Option<bool>,
+    pub clock_id: i64,
+    /// The clock ID of the master the camera synchronizes to.
+    pub parent_clock_id: i64,
+    /// The offset from the master clock in nanoseconds.
+    pub offset_from_master: i64,
+}
+
+impl PtpStatus {
+    /// Return whether the camera is the master or a slave which is locked to
+    /// its master within `tolerance_ns`.
+    pub fn is_locked(&self, tolerance_ns: i64) -> bool {
+        match self.state {
+            PtpState::Master => true,
+            PtpState::Slave => {
+                self.servo_locked != Some(false) && self.offset_from_master.abs() <= tolerance_ns
+            }
+            _ => false,
+        }
+    }
+}
+
+impl InstantCamera<'_> {
+    /// Enable or disable PTP (IEEE 1588) clock synchronization of an open
+    /// camera.
+    pub fn enable_ptp(&self, enable: bool) -> PylonResult<()> {
+        let node_map = self.node_map()?;
+        let nodes = PtpNodes::of(&node_map)?;
+        node_map.boolean_node(nodes.enable)?.set_value(enable)
+    }
+
+    /// Latch and read the PTP data set of an open camera.
+    pub fn ptp_status(&self) -> PylonResult<PtpStatus> {
+        let node_map = self.node_map()?;
+        let nodes = PtpNodes::of(&node_map)?;
+        node_map.command_node(nodes.latch)?.execute(true)?;
+        let servo_locked = match nodes.servo_status {
+            Some(name) if node_map.has_node(name) => {
+                Some(node_map.enum_node(name)?.value()? == "Locked")
+            }
+            _ => None,
+        };
+        Ok(PtpStatus {
+            state: PtpState::from_entry(&node_map.enum_node(nodes.status)?.value()?),
+            servo_locked,
+            clock_id: node_map.integer_node(nodes.clock_id)?.value()?,
+            parent_clock_id: node_map.integer_node(nodes.parent_clock_id)?.value()?,
+            offset_from_master: node_map.integer_node(nodes.offset_from_master)?.value()?,
+        })
+    }
+}
+
+/// Wait until the PTP clocks of all cameras are locked, see
+/// [PtpStatus::is_locked], and return their status.
+///
+/// PTP must have been enabled on the cameras with [InstantCamera::enable_ptp].
+/// Negotiating a master and locking usually takes several seconds. An error
+/// listing the status of the cameras is returned after `timeout`.
+pub fn wait_for_ptp_lock(
+    cameras: &[&InstantCamera],
+    tolerance_ns: i64,
+    timeout: Duration,
+) -> PylonResult<Vec<PtpStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let statuses = cameras
+            .iter()
+            .map(|camera| camera.ptp_status())
+            .collect::<PylonResult<Vec<_>>>()?;
+        if all_locked(&statuses, tolerance_ns) {
+            return Ok(statuses);
+        }
+        if Instant::now() >= deadline {
+            return Err(PylonError::new(format!(
+                "PTP did not lock within {:?}: {:?}",
+                timeout, statuses
+            )));
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Return whether all cameras are locked to a single master.
+fn all_locked(statuses: &[PtpStatus], tolerance_ns: i64) -> bool {
+    let masters = statuses
+        .iter()
+        .filter(|status| status.state == PtpState::Master)
+        .count();
+    // Cameras which have not agreed on a master yet may both claim it.
+    masters <= 1 && statuses.iter().all(|status| status.is_locked(tolerance_ns))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(state: PtpState, servo_locked: Option<bool>, offset_from_master: i64) -> PtpStatus {
+        PtpStatus {
+            state,
+            servo_locked,
+            clock_id: 1,
+            parent_clock_id: 2,
+            offset_from_master,
+        }
+    }
+
+    #[test]
+    fn states_are_parsed() {
+        assert_eq!(PtpState::from_entry("Slave"), PtpState::Slave);
+        assert_eq!(PtpState::from_entry("Master"), PtpState::Master);
+        assert_eq!(PtpState::from_entry("PreMaster"), PtpState::PreMaster);
+        assert_eq!(
+            PtpState::from_entry("Unknown"),
+            PtpState::Other("Unknown".to_string())
+        );
+    }
+
+    #[test]
+    fn slave_is_locked_within_tolerance() {
+        assert!(status(PtpState::Slave, None, -500).is_locked(1000));
+        assert!(status(PtpState::Slave, Some(true), 1000).is_locked(1000));
+        assert!(!status(PtpState::Slave, Some(true), 1001).is_locked(1000));
+        assert!(!status(PtpState::Slave, Some(false), 0).is_locked(1000));
+    }
+
+    #[test]
+    fn master_is_locked() {
+        assert!(status(PtpState::Master, None, 1_000_000).is_locked(1000));
+        assert!(!status(PtpState::Listening, None, 0).is_locked(1000));
+        assert!(!status(PtpState::Uncalibrated, Some(true), 0).is_locked(1000));
+    }
+
+    #[test]
+    fn cameras_need_a_single_master() {
+        let master = status(PtpState::Master, None, 0);
+        let slave = status(PtpState::Slave, Some(true), 10);
+        assert!(all_locked(&[master.clone(), slave.clone()], 1000));
+        assert!(!all_locked(&[master.clone(), master.clone()], 1000));
+        assert!(!all_locked(
+            &[master, status(PtpState::Slave, Some(true), 5000)],
+            1000
+        ));
+        assert!(all_locked(&[slave.clone(), slave], 1000));
+    }
+}
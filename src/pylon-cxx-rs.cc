@@ -3,8 +3,14 @@
 #include <fcntl.h>
 #endif
 #include <memory>
+#include <stdexcept>
 #include "pylon/PylonIncludes.h"
+#include "pylon/gige/GigETransportLayer.h"
+#include "pylon/SoftwareTriggerConfiguration.h"
+#include "pylon/AcquireSingleFrameConfiguration.h"
+#include "pylon/AcquireContinuousConfiguration.h"
 #include "pylon-cxx-rs.h"
+#include "pylon-cxx/src/lib.rs.h"
 
 std::unique_ptr<std::vector<std::string>> to_std_vec_str(const Pylon::StringList_t& names)
 {
@@ -46,6 +52,81 @@ namespace Pylon
         return es;
     }
 
+    ETimeoutHandling convert_timeout_handling(TimeoutHandling timeout_handling)
+    {
+        if (timeout_handling == TimeoutHandling::ThrowException)
+        {
+            return TimeoutHandling_ThrowException;
+        }
+        else if (timeout_handling == TimeoutHandling::Return)
+        {
+            return TimeoutHandling_Return;
+        }
+        else
+        {
+            throw std::exception();
+        }
+    }
+
+    EGrabLoop convert_grab_loop(GrabLoop grab_loop)
+    {
+        if (grab_loop == GrabLoop::ProvidedByInstantCamera)
+        {
+            return GrabLoop_ProvidedByInstantCamera;
+        }
+        else if (grab_loop == GrabLoop::ProvidedByUser)
+        {
+            return GrabLoop_ProvidedByUser;
+        }
+        else
+        {
+            throw std::exception();
+        }
+    }
+
+    ERegistrationMode convert_registration_mode(RegistrationMode mode)
+    {
+        if (mode == RegistrationMode::Append)
+        {
+            return RegistrationMode_Append;
+        }
+        else if (mode == RegistrationMode::ReplaceAll)
+        {
+            return RegistrationMode_ReplaceAll;
+        }
+        else
+        {
+            throw std::exception();
+        }
+    }
+
+    bool has_access_mode(uint32_t access_modes, AccessMode mode)
+    {
+        return (access_modes & (1u << static_cast<uint32_t>(mode))) != 0;
+    }
+
+    AccessModeSet convert_access_modes(uint32_t access_modes)
+    {
+        AccessModeSet result;
+        if (has_access_mode(access_modes, AccessMode::Control))
+        {
+            result.set(Control);
+        }
+        if (has_access_mode(access_modes, AccessMode::Stream))
+        {
+            result.set(Stream);
+        }
+        if (has_access_mode(access_modes, AccessMode::Event))
+        {
+            result.set(Event);
+        }
+        if (has_access_mode(access_modes, AccessMode::Exclusive))
+        {
+            result.set(Exclusive);
+        }
+        return result;
+    }
+
     std::unique_ptr<CInstantCamera> tl_factory_create_first_device()
     {
         // Create an instant camera object with the camera device found first.
@@ -72,6 +153,61 @@ namespace Pylon
         return result;
     }
 
+    DeviceAccessibility tl_factory_is_device_accessible(const CDeviceInfo &device_info, uint32_t access_modes)
+    {
+        EDeviceAccessiblityInfo info = Accessibility_Unknown;
+        CTlFactory::GetInstance().IsDeviceAccessible(device_info, convert_access_modes(access_modes), &info);
+        switch (info)
+        {
+        case Accessibility_Ok:
+            return DeviceAccessibility::Ok;
+        case Accessibility_Opened:
+            return DeviceAccessibility::Opened;
+        case Accessibility_OpenedExclusively:
+            return DeviceAccessibility::OpenedExclusively;
+        case Accessibility_NotReachable:
+            return DeviceAccessibility::NotReachable;
+        default:
+            return DeviceAccessibility::Unknown;
+        }
+    }
+
+    GigETransportLayerHandle::GigETransportLayerHandle(IGigETransportLayer *tl) : tl(tl)
+    {
+    }
+
+    GigETransportLayerHandle::~GigETransportLayerHandle()
+    {
+        CTlFactory::GetInstance().ReleaseTl(tl);
+    }
+
+    std::unique_ptr<GigETransportLayerHandle> tl_factory_create_gige_transport_layer()
+    {
+        ITransportLayer *tl = CTlFactory::GetInstance().CreateTl(BaslerGigEDeviceClass);
+        IGigETransportLayer *gige_tl = dynamic_cast<IGigETransportLayer *>(tl);
+        if (gige_tl == NULL)
+        {
+            if (tl != NULL)
+            {
+                CTlFactory::GetInstance().ReleaseTl(tl);
+            }
+            throw std::runtime_error("GigE transport layer is not available");
+        }
+        return std::make_unique<GigETransportLayerHandle>(gige_tl);
+    }
+
+    void gige_transport_layer_issue_action_command(const std::unique_ptr<GigETransportLayerHandle> &tl, uint32_t device_key, uint32_t group_key, uint32_t group_mask, rust::Str c_broadcast_address)
+    {
+        Pylon::String_t broadcast_address = Pylon::String_t(c_broadcast_address.data(), c_broadcast_address.length());
+        tl->tl->IssueActionCommand(device_key, group_key, group_mask, broadcast_address);
+    }
+
+    void gige_transport_layer_issue_scheduled_action_command(const std::unique_ptr<GigETransportLayerHandle> &tl, uint32_t device_key, uint32_t group_key, uint32_t group_mask, uint64_t action_time_ns, rust::Str c_broadcast_address)
+    {
+        Pylon::String_t broadcast_address = Pylon::String_t(c_broadcast_address.data(), c_broadcast_address.length());
+        tl->tl->IssueScheduledActionCommand(device_key, group_key, group_mask, action_time_ns, broadcast_address);
+    }
+
     std::unique_ptr<CDeviceInfo> instant_camera_get_device_info(const std::unique_ptr<CInstantCamera> &camera)
     {
         // According to InstantCamera.h, `GetDeviceInfo()` does not throw C++ exceptions.
@@ -83,6 +219,33 @@ namespace Pylon
         camera->Open();
     }
 
+    void instant_camera_open_with_access_mode(const std::unique_ptr<CInstantCamera> &camera, uint32_t access_modes)
+    {
+        if (has_access_mode(access_modes, AccessMode::Monitor))
+        {
+            // Monitor mode is a property of the instant camera, it opens the
+            // device without control access.
+            CBooleanParameter(camera->GetInstantCameraNodeMap(), "MonitorModeActive").SetValue(true);
+        }
+        else if (convert_access_modes(access_modes) != (Control | Stream | Event))
+        {
+            // The instant camera opens the device with control, stream and
+            // event access. For other access modes the device is opened first,
+            // the instant camera keeps the access modes of an already opened
+            // device.
+            IPylonDevice *device = camera->GetDevice();
+            if (device == NULL)
+            {
+                throw std::runtime_error("No device attached to the camera");
+            }
+            if (!device->IsOpen())
+            {
+                device->Open(convert_access_modes(access_modes));
+            }
+        }
+        camera->Open();
+    }
+
     bool instant_camera_is_open(const std::unique_ptr<CInstantCamera> &camera)
     {
         return camera->IsOpen();
@@ -167,6 +330,16 @@ namespace Pylon
         camera->StartGrabbing(count, convert_grab_strategy(strategy));
     }
 
+    void instant_camera_start_grabbing_with_grab_loop(const std::unique_ptr<CInstantCamera> &camera, GrabStrategy strategy, GrabLoop grab_loop)
+    {
+        camera->StartGrabbing(convert_grab_strategy(strategy), convert_grab_loop(grab_loop));
+    }
+
+    void instant_camera_start_grabbing_with_count_and_grab_loop(const std::unique_ptr<CInstantCamera> &camera, uint32_t count, GrabStrategy strategy, GrabLoop grab_loop)
+    {
+        camera->StartGrabbing(count, convert_grab_strategy(strategy), convert_grab_loop(grab_loop));
+    }
+
     void instant_camera_stop_grabbing(const std::unique_ptr<CInstantCamera> &camera)
     {
         camera->StopGrabbing();
@@ -178,6 +351,284 @@ namespace Pylon
         return camera->IsGrabbing();
     }
 
+    bool instant_camera_is_camera_device_removed(const std::unique_ptr<CInstantCamera> &camera)
+    {
+        // According to InstantCamera.h, `IsCameraDeviceRemoved()` does not throw C++ exceptions.
+        return camera->IsCameraDeviceRemoved();
+    }
+
+    void instant_camera_destroy_device(const std::unique_ptr<CInstantCamera> &camera)
+    {
+        camera->DestroyDevice();
+    }
+
+    void instant_camera_attach_device(const std::unique_ptr<CInstantCamera> &camera, const CDeviceInfo &device_info)
+    {
+        camera->Attach(CTlFactory::GetInstance().CreateDevice(device_info));
+    }
+
+    RustConfigurationEventHandler::RustConfigurationEventHandler(rust::Box<ConfigurationEventHandlerBox> handler) : handler(std::move(handler))
+    {
+    }
+
+    RustConfigurationEventHandler::~RustConfigurationEventHandler()
+    {
+    }
+
+    void RustConfigurationEventHandler::OnAttach(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::Attach);
+    }
+
+    void RustConfigurationEventHandler::OnAttached(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::Attached);
+    }
+
+    void RustConfigurationEventHandler::OnDetach(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::Detach);
+    }
+
+    void RustConfigurationEventHandler::OnDetached(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::Detached);
+    }
+
+    void RustConfigurationEventHandler::OnDestroy(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::Destroy);
+    }
+
+    void RustConfigurationEventHandler::OnDestroyed(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::Destroyed);
+    }
+
+    void RustConfigurationEventHandler::OnOpen(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::Open);
+    }
+
+    void RustConfigurationEventHandler::OnOpened(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::Opened);
+    }
+
+    void RustConfigurationEventHandler::OnClose(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::Close);
+    }
+
+    void RustConfigurationEventHandler::OnClosed(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::Closed);
+    }
+
+    void RustConfigurationEventHandler::OnGrabStart(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::GrabStart);
+    }
+
+    void RustConfigurationEventHandler::OnGrabStarted(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::GrabStarted);
+    }
+
+    void RustConfigurationEventHandler::OnGrabStop(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::GrabStop);
+    }
+
+    void RustConfigurationEventHandler::OnGrabStopped(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::GrabStopped);
+    }
+
+    void RustConfigurationEventHandler::OnGrabError(CInstantCamera &camera, const char *message)
+    {
+        configuration_event_handler_on_grab_error(*handler, camera, rust::Str(message));
+    }
+
+    void RustConfigurationEventHandler::OnCameraDeviceRemoved(CInstantCamera &camera)
+    {
+        configuration_event_handler_on_event(*handler, camera, ConfigurationEvent::CameraDeviceRemoved);
+    }
+
+    std::unique_ptr<RustConfigurationEventHandler> new_configuration_event_handler(rust::Box<ConfigurationEventHandlerBox> handler)
+    {
+        return std::make_unique<RustConfigurationEventHandler>(std::move(handler));
+    }
+
+    void instant_camera_register_configuration_event_handler(const std::unique_ptr<CInstantCamera> &camera, const std::unique_ptr<RustConfigurationEventHandler> &handler, RegistrationMode mode)
+    {
+        // The handler is owned by Rust, which deregisters it before deleting it.
+        camera->RegisterConfiguration(handler.get(), convert_registration_mode(mode), Cleanup_None);
+    }
+
+    bool instant_camera_deregister_configuration_event_handler(const std::unique_ptr<CInstantCamera> &camera, const std::unique_ptr<RustConfigurationEventHandler> &handler)
+    {
+        return camera->DeregisterConfiguration(handler.get());
+    }
+
+    void instant_camera_register_builtin_configuration(const std::unique_ptr<CInstantCamera> &camera, BuiltinConfiguration configuration, RegistrationMode mode)
+    {
+        CConfigurationEventHandler *handler;
+        if (configuration == BuiltinConfiguration::SoftwareTrigger)
+        {
+            handler = new CSoftwareTriggerConfiguration();
+        }
+        else if (configuration == BuiltinConfiguration::AcquireSingleFrame)
+        {
+            handler = new CAcquireSingleFrameConfiguration();
+        }
+        else if (configuration == BuiltinConfiguration::AcquireContinuous)
+        {
+            handler = new CAcquireContinuousConfiguration();
+        }
+        else
+        {
+            throw std::exception();
+        }
+        // The camera deletes the configuration when it is deregistered.
+        camera->RegisterConfiguration(handler, convert_registration_mode(mode), Cleanup_Delete);
+    }
+
+    RustConfiguration::RustConfiguration(rust::Box<ConfigurationBox> configuration) : configuration(std::move(configuration))
+    {
+    }
+
+    RustConfiguration::~RustConfiguration()
+    {
+    }
+
+    void RustConfiguration::OnOpened(CInstantCamera &camera)
+    {
+        // An error is thrown as `rust::Error` and propagates out of `Open()`.
+        configuration_on_opened(*configuration, camera);
+    }
+
+    void instant_camera_register_configuration(const std::unique_ptr<CInstantCamera> &camera, rust::Box<ConfigurationBox> configuration, RegistrationMode mode)
+    {
+        // The camera deletes the configuration when it is deregistered.
+        camera->RegisterConfiguration(new RustConfiguration(std::move(configuration)), convert_registration_mode(mode), Cleanup_Delete);
+    }
+
+    RustBufferFactory::RustBufferFactory(rust::Box<BufferAllocatorBox> allocator) : allocator(std::move(allocator))
+    {
+    }
+
+    RustBufferFactory::~RustBufferFactory()
+    {
+    }
+
+    void RustBufferFactory::AllocateBuffer(size_t buffer_size, void **created_buffer, intptr_t &buffer_context)
+    {
+        // An error is thrown as `rust::Error`, which makes pylon fail to start grabbing.
+        *created_buffer = reinterpret_cast<void *>(buffer_allocator_allocate(*allocator, buffer_size, buffer_context));
+    }
+
+    void RustBufferFactory::FreeBuffer(void *created_buffer, intptr_t buffer_context)
+    {
+        buffer_allocator_free(*allocator, reinterpret_cast<size_t>(created_buffer), buffer_context);
+    }
+
+    void RustBufferFactory::DestroyBufferFactory()
+    {
+        delete this;
+    }
+
+    void instant_camera_set_buffer_factory(const std::unique_ptr<CInstantCamera> &camera, rust::Box<BufferAllocatorBox> allocator)
+    {
+        // pylon calls `DestroyBufferFactory()` when all buffers have been freed.
+        camera->SetBufferFactory(new RustBufferFactory(std::move(allocator)), Cleanup_Delete);
+    }
+
+    RustImageEventHandler::RustImageEventHandler(rust::Box<ImageEventHandlerBox> handler) : handler(std::move(handler))
+    {
+    }
+
+    RustImageEventHandler::~RustImageEventHandler()
+    {
+    }
+
+    void RustImageEventHandler::OnImageGrabbed(CInstantCamera &camera, const CGrabResultPtr &grab_result)
+    {
+        // The copy shares the grab result with pylon.
+        image_event_handler_on_image_grabbed(*handler, camera, std::make_unique<CGrabResultPtr>(grab_result));
+    }
+
+    std::unique_ptr<RustImageEventHandler> new_image_event_handler(rust::Box<ImageEventHandlerBox> handler)
+    {
+        return std::make_unique<RustImageEventHandler>(std::move(handler));
+    }
+
+    void instant_camera_register_image_event_handler(const std::unique_ptr<CInstantCamera> &camera, const std::unique_ptr<RustImageEventHandler> &handler, RegistrationMode mode)
+    {
+        // The handler is owned by Rust, which deregisters it before deleting it.
+        camera->RegisterImageEventHandler(handler.get(), convert_registration_mode(mode), Cleanup_None);
+    }
+
+    bool instant_camera_deregister_image_event_handler(const std::unique_ptr<CInstantCamera> &camera, const std::unique_ptr<RustImageEventHandler> &handler)
+    {
+        return camera->DeregisterImageEventHandler(handler.get());
+    }
+
+    RustCameraEventHandler::RustCameraEventHandler(rust::Box<CameraEventHandlerBox> handler) : handler(std::move(handler))
+    {
+    }
+
+    RustCameraEventHandler::~RustCameraEventHandler()
+    {
+    }
+
+    void RustCameraEventHandler::OnCameraEvent(CInstantCamera &camera, intptr_t user_provided_id, GenApi::INode *node)
+    {
+        // Each handler is registered for a single node, so the node is known on the Rust side.
+        camera_event_handler_on_camera_event(*handler, camera);
+    }
+
+    std::unique_ptr<RustCameraEventHandler> new_camera_event_handler(rust::Box<CameraEventHandlerBox> handler)
+    {
+        return std::make_unique<RustCameraEventHandler>(std::move(handler));
+    }
+
+    void instant_camera_register_camera_event_handler(const std::unique_ptr<CInstantCamera> &camera, const std::unique_ptr<RustCameraEventHandler> &handler, rust::Str c_name)
+    {
+        Pylon::String_t name = Pylon::String_t(c_name.data(), c_name.length());
+        // The handler is owned by Rust, which deregisters it before deleting it.
+        camera->RegisterCameraEventHandler(handler.get(), name, 0, RegistrationMode_Append, Cleanup_None);
+    }
+
+    bool instant_camera_deregister_camera_event_handler(const std::unique_ptr<CInstantCamera> &camera, const std::unique_ptr<RustCameraEventHandler> &handler, rust::Str c_name)
+    {
+        Pylon::String_t name = Pylon::String_t(c_name.data(), c_name.length());
+        return camera->DeregisterCameraEventHandler(handler.get(), name);
+    }
+
+    std::unique_ptr<CDeviceInfo> instant_camera_ref_get_device_info(const CInstantCamera &camera)
+    {
+        // According to InstantCamera.h, `GetDeviceInfo()` does not throw C++ exceptions.
+        return std::make_unique<CDeviceInfo>(camera.GetDeviceInfo());
+    }
+
+    const MyNodeMap& instant_camera_ref_get_node_map(const CInstantCamera &camera)
+    {
+        return ((CInstantCamera&)camera).GetNodeMap();
+    }
+
+    bool instant_camera_ref_is_grabbing(const CInstantCamera &camera)
+    {
+        // According to InstantCamera.h, `IsGrabbing()` does not throw C++ exceptions.
+        return camera.IsGrabbing();
+    }
+
+    bool instant_camera_ref_is_camera_device_removed(const CInstantCamera &camera)
+    {
+        // According to InstantCamera.h, `IsCameraDeviceRemoved()` does not throw C++ exceptions.
+        return camera.IsCameraDeviceRemoved();
+    }
+
     #if defined(FEATURE_STREAM_UNIX)
     int instant_camera_wait_object_fd(const std::unique_ptr<CInstantCamera> &camera) {
       return camera->GetGrabResultWaitObject().GetFd();
@@ -192,21 +643,36 @@ namespace Pylon
 
     bool instant_camera_retrieve_result(const std::unique_ptr<CInstantCamera> &camera, uint32_t timeout, std::unique_ptr<CGrabResultPtr> &result, TimeoutHandling timeout_handling)
     {
-        ETimeoutHandling eth;
+        return camera->RetrieveResult(timeout, *result, convert_timeout_handling(timeout_handling));
+    }
 
-        if (timeout_handling == TimeoutHandling::ThrowException)
-        {
-            eth = TimeoutHandling_ThrowException;
-        }
-        else if (timeout_handling == TimeoutHandling::Return)
-        {
-            eth = TimeoutHandling_Return;
-        }
-        else
-        {
-            throw std::exception();
-        }
-        return camera->RetrieveResult(timeout, *result, eth);
+    void instant_camera_grab_one(const std::unique_ptr<CInstantCamera> &camera, uint32_t timeout, std::unique_ptr<CGrabResultPtr> &result)
+    {
+        // With `TimeoutHandling_ThrowException`, a timeout throws and a failed
+        // grab returns false, which the caller checks with `GrabSucceeded()`.
+        camera->GrabOne(timeout, *result, TimeoutHandling_ThrowException);
+    }
+
+    bool instant_camera_can_wait_for_frame_trigger_ready(const std::unique_ptr<CInstantCamera> &camera)
+    {
+        return camera->CanWaitForFrameTriggerReady();
+    }
+
+    bool instant_camera_wait_for_frame_trigger_ready(const std::unique_ptr<CInstantCamera> &camera, uint32_t timeout, TimeoutHandling timeout_handling)
+    {
+        return camera->WaitForFrameTriggerReady(timeout, convert_timeout_handling(timeout_handling));
+    }
+
+    void instant_camera_execute_software_trigger(const std::unique_ptr<CInstantCamera> &camera)
+    {
+        camera->ExecuteSoftwareTrigger();
+    }
+
+    bool node_map_has_node(const MyNodeMap& node_map, rust::Str c_name)
+    {
+        GenApi::INodeMap& nodemap = (GenApi::INodeMap&)node_map;
+        Pylon::String_t name = Pylon::String_t(c_name.data(), c_name.length());
+        return nodemap.GetNode(name) != NULL;
     }
 
     std::unique_ptr<CBooleanParameter> node_map_get_boolean_parameter(const MyNodeMap& node_map, rust::Str c_name)
@@ -244,6 +710,13 @@ namespace Pylon
         return std::make_unique<CCommandParameter>(CCommandParameter(nodemap, name));
     }
 
+    std::unique_ptr<CStringParameter> node_map_get_string_parameter(const MyNodeMap& node_map, rust::Str c_name)
+    {
+        GenApi::INodeMap& nodemap = (GenApi::INodeMap&)node_map;
+        Pylon::String_t name = Pylon::String_t(c_name.data(), c_name.length());
+        return std::make_unique<CStringParameter>(CStringParameter(nodemap, name));
+    }
+
     bool boolean_node_get_value(const std::unique_ptr<CBooleanParameter> &node)
     {
         return node->GetValue();
@@ -327,12 +800,31 @@ namespace Pylon
         command_node->Execute(verify);
     }
 
+    rust::String string_node_get_value(const std::unique_ptr<CStringParameter> &node)
+    {
+        // This copies the data.
+        Pylon::String_t value = node->GetValue();
+        return rust::String(value.c_str(), value.length());
+    }
+
+    void string_node_set_value(const std::unique_ptr<CStringParameter> &node, rust::Str c_value)
+    {
+        Pylon::String_t value = Pylon::String_t(c_value.data(), c_value.length());
+        node->SetValue(value);
+    }
+
     // CGrabResultPtr
     std::unique_ptr<CGrabResultPtr> new_grab_result_ptr()
     {
         return std::make_unique<CGrabResultPtr>();
     }
 
+    std::unique_ptr<CGrabResultPtr> grab_result_clone(const std::unique_ptr<CGrabResultPtr> &grab_result)
+    {
+        // Copying a `CGrabResultPtr` shares the grab result and increments its reference count.
+        return std::make_unique<CGrabResultPtr>(*grab_result);
+    }
+
     bool grab_result_grab_succeeded(const std::unique_ptr<CGrabResultPtr> &grab_result)
     {
         return (*grab_result)->GrabSucceeded();
@@ -418,6 +910,11 @@ namespace Pylon
         return (*grab_result)->GetBlockID();
     }
 
+    intptr_t grab_result_buffer_context(const std::unique_ptr<CGrabResultPtr> &grab_result)
+    {
+        return (*grab_result)->GetBufferContext();
+    }
+
     uint64_t grab_result_time_stamp(const std::unique_ptr<CGrabResultPtr> &grab_result)
     {
         return (*grab_result)->GetTimeStamp();
@@ -435,6 +932,11 @@ namespace Pylon
         return (*grab_result)->GetImageSize();
     }
 
+    size_t grab_result_number_of_skipped_images(const std::unique_ptr<CGrabResultPtr> &grab_result)
+    {
+        return (*grab_result)->GetNumberOfSkippedImages();
+    }
+
     const MyNodeMap& grab_result_get_chunk_data_node_map(const std::unique_ptr<CGrabResultPtr> &grab_result)
     {
         return (*grab_result)->GetChunkDataNodeMap();
@@ -474,6 +976,24 @@ namespace Pylon
         return rust::String(device_info->GetModelName());
     }
 
+    rust::String device_info_get_serial_number(const std::unique_ptr<CDeviceInfo> &device_info)
+    {
+        // This copies the data.
+        return rust::String(device_info->GetSerialNumber());
+    }
+
+    rust::String device_info_get_full_name(const std::unique_ptr<CDeviceInfo> &device_info)
+    {
+        // This copies the data.
+        return rust::String(device_info->GetFullName());
+    }
+
+    rust::String device_info_get_device_class(const std::unique_ptr<CDeviceInfo> &device_info)
+    {
+        // This copies the data.
+        return rust::String(device_info->GetDeviceClass());
+    }
+
     #if defined(FEATURE_STREAM_WINDOWS)
     bool wait_object_wait(const std::unique_ptr<WaitObject> &wait_object, uint64_t timeout) {
         return wait_object->Wait(timeout);
@@ -0,0 +1,243 @@
+use std::time::{Duration, Instant};
+
+use crate::{ffi, DeviceInfo, InstantCamera, PylonError, PylonResult, TlFactory};
+
+/// How an [InstantCamera] reconnects to a camera device which has been removed.
+///
+/// Set it with [InstantCamera::set_reconnect_policy] before opening the
+/// camera. A [GrabWorker](crate::GrabWorker), and so a
+/// [CameraGroup](crate::CameraGroup), reconnects automatically: it delivers
+/// the error of kind [ErrorKind::DeviceRemoved](crate::ErrorKind::DeviceRemoved)
+/// and then keeps grabbing once the camera has reappeared. When retrieving
+/// results directly, call [InstantCamera::reconnect] after
+/// [InstantCamera::retrieve_result] returned that error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    timeout: Duration,
+    poll_interval: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            poll_interval: Duration::from_millis(500),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// How long to wait for the camera to reappear. The default is 30 seconds.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    /// How often to enumerate the devices while waiting for the camera to
+    /// reappear. The default is 500 milliseconds.
+    pub fn poll_interval(self, poll_interval: Duration) -> Self {
+        Self {
+            poll_interval,
+            ..self
+        }
+    }
+
+    /// How long to wait before enumerating again after waiting for `elapsed`,
+    /// or `None` once the timeout has expired. The last delay ends at the
+    /// timeout.
+    fn next_delay(&self, elapsed: Duration) -> Option<Duration> {
+        match self.timeout.checked_sub(elapsed) {
+            Some(remaining) if remaining > Duration::ZERO => {
+                Some(self.poll_interval.min(remaining))
+            }
+            _ => None,
+        }
+    }
+
+    /// Enumerate the devices until one with `serial_number` is found.
+    fn wait_for_device(
+        &self,
+        tl_factory: &TlFactory,
+        serial_number: &str,
+    ) -> PylonResult<DeviceInfo> {
+        let start = Instant::now();
+        loop {
+            if let Some(device_info) = find_device(tl_factory, serial_number)? {
+                return Ok(device_info);
+            }
+            match self.next_delay(start.elapsed()) {
+                Some(delay) => std::thread::sleep(delay),
+                None => {
+                    return Err(PylonError::new(format!(
+                        "Camera {} did not reappear within {:?}",
+                        serial_number, self.timeout
+                    )))
+                }
+            }
+        }
+    }
+}
+
+impl InstantCamera<'_> {
+    /// Set or clear the policy used by [InstantCamera::reconnect].
+    ///
+    /// While a policy is set, the node values are saved once, when the camera
+    /// is opened or now if the camera is open already. Reconnecting restores
+    /// them. Call [InstantCamera::update_reconnect_snapshot] to save values
+    /// changed later.
+    pub fn set_reconnect_policy(&self, policy: Option<ReconnectPolicy>) -> PylonResult<()> {
+        let enabled = policy.is_some();
+        self.state.reconnect_policy.replace(policy);
+        if !enabled {
+            self.state.node_map_snapshot.replace(None);
+        } else if self.is_open()? {
+            self.save_node_map_snapshot()?;
+        }
+        Ok(())
+    }
+
+    /// Save the current node values to restore when reconnecting, replacing
+    /// the values saved before.
+    ///
+    /// Saving the node values can take seconds, e.g. for GigE cameras. An
+    /// error is returned if no reconnect policy is set.
+    pub fn update_reconnect_snapshot(&self) -> PylonResult<()> {
+        self.required_reconnect_policy()?;
+        self.state.node_map_snapshot.replace(None);
+        self.save_node_map_snapshot()
+    }
+
+    /// Reconnect to the camera device after it has been removed.
+    ///
+    /// This destroys the removed device, waits until a device with the same
+    /// serial number is enumerated again, attaches and opens it, restores the
+    /// node values saved last and, if grabbing was not stopped with
+    /// [InstantCamera::stop_grabbing], restarts grabbing with the options of
+    /// the last [InstantCamera::start_grabbing] call.
+    ///
+    /// This blocks until the camera is reconnected or the timeout of the
+    /// policy set with [InstantCamera::set_reconnect_policy] expires. An error
+    /// is returned if no policy is set.
+    pub fn reconnect(&self) -> PylonResult<()> {
+        let policy = self.required_reconnect_policy()?;
+        let serial_number = self.destroy_removed_device()?;
+        let device_info =
+            policy.wait_for_device(&TlFactory::instance(self._lib), &serial_number)?;
+        self.reattach(&device_info)
+    }
+
+    /// Async version of [InstantCamera::reconnect].
+    ///
+    /// Waiting for the camera to reappear runs on tokio's blocking thread
+    /// pool. Opening the camera and restoring its node values runs on the
+    /// calling task.
+    #[cfg(feature = "stream")]
+    pub async fn reconnect_async(&self) -> PylonResult<()> {
+        let policy = self.required_reconnect_policy()?;
+        let serial_number = self.destroy_removed_device()?;
+        let pylon = self._lib.retain();
+        let device_info = crate::blocking::spawn_blocking(move || {
+            policy.wait_for_device(&TlFactory::instance(&pylon), &serial_number)
+        })
+        .await?;
+        self.reattach(&device_info)
+    }
+
+    /// Return whether a reconnect policy is set.
+    pub(crate) fn has_reconnect_policy(&self) -> bool {
+        self.state.reconnect_policy.borrow().is_some()
+    }
+
+    /// Save the node values to restore when reconnecting, if a reconnect
+    /// policy is set and they have not been saved yet.
+    pub(crate) fn save_node_map_snapshot(&self) -> PylonResult<()> {
+        if self.has_reconnect_policy() && self.state.node_map_snapshot.borrow().is_none() {
+            let snapshot = self.node_map()?.save_to_string()?;
+            self.state.node_map_snapshot.replace(Some(snapshot));
+        }
+        Ok(())
+    }
+
+    fn required_reconnect_policy(&self) -> PylonResult<ReconnectPolicy> {
+        self.state.reconnect_policy.borrow().clone().ok_or_else(|| {
+            PylonError::new("No reconnect policy is set, see `set_reconnect_policy`")
+        })
+    }
+
+    /// Destroy the device and return its serial number.
+    fn destroy_removed_device(&self) -> PylonResult<String> {
+        let serial_number = self.device_info().serial_number()?;
+        self.reset_async_wait();
+        ffi::instant_camera_destroy_device(&self.inner)?;
+        Ok(serial_number)
+    }
+
+    fn reattach(&self, device_info: &DeviceInfo) -> PylonResult<()> {
+        ffi::instant_camera_attach_device(&self.inner, &device_info.inner)?;
+        self.open()?;
+        let node_map_snapshot = self.state.node_map_snapshot.borrow().clone();
+        if let Some(node_map_snapshot) = node_map_snapshot {
+            self.node_map()?.load_from_string(node_map_snapshot, true)?;
+        }
+        let grab_options = self.state.grab_options.borrow().clone();
+        if let Some(grab_options) = grab_options {
+            self.start_grabbing(&grab_options)?;
+        }
+        Ok(())
+    }
+}
+
+fn find_device(tl_factory: &TlFactory, serial_number: &str) -> PylonResult<Option<DeviceInfo>> {
+    for device in tl_factory.enumerate_devices()? {
+        if device.serial_number()? == serial_number {
+            return Ok(Some(device));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReconnectPolicy;
+    use std::time::Duration;
+
+    fn policy() -> ReconnectPolicy {
+        ReconnectPolicy::default()
+            .timeout(Duration::from_millis(1200))
+            .poll_interval(Duration::from_millis(500))
+    }
+
+    #[test]
+    fn delay_is_the_poll_interval() {
+        let policy = policy();
+        assert_eq!(
+            policy.next_delay(Duration::ZERO),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(
+            policy.next_delay(Duration::from_millis(600)),
+            Some(Duration::from_millis(500))
+        );
+    }
+
+    #[test]
+    fn last_delay_ends_at_the_timeout() {
+        assert_eq!(
+            policy().next_delay(Duration::from_millis(1000)),
+            Some(Duration::from_millis(200))
+        );
+    }
+
+    #[test]
+    fn no_delay_after_the_timeout() {
+        let policy = policy();
+        assert_eq!(policy.next_delay(Duration::from_millis(1200)), None);
+        assert_eq!(policy.next_delay(Duration::from_secs(5)), None);
+    }
+
+    #[test]
+    fn default_policy() {
+        let policy = ReconnectPolicy::default();
+        assert_eq!(policy.timeout, Duration::from_secs(30));
+        assert_eq!(policy.poll_interval, Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{ffi, CxxResultExt, InstantCamera, PylonResult, TimeoutHandling};
+
+impl InstantCamera<'_> {
+    /// Return whether [InstantCamera::wait_for_frame_trigger_ready] can be
+    /// used with the camera and its current configuration.
+    pub fn can_wait_for_frame_trigger_ready(&self) -> PylonResult<bool> {
+        ffi::instant_camera_can_wait_for_frame_trigger_ready(&self.inner).into_rust()
+    }
+
+    /// Wait until the camera is ready to accept a frame trigger.
+    ///
+    /// Returns `false` if the camera did not become ready within `timeout_ms`
+    /// and `timeout_handling` is [TimeoutHandling::Return]. Triggering only
+    /// when ready avoids overtriggering, which makes the camera ignore
+    /// triggers.
+    pub fn wait_for_frame_trigger_ready(
+        &self,
+        timeout_ms: u32,
+        timeout_handling: TimeoutHandling,
+    ) -> PylonResult<bool> {
+        ffi::instant_camera_wait_for_frame_trigger_ready(&self.inner, timeout_ms, timeout_handling)
+            .into_rust()
+    }
+
+    /// Execute the `TriggerSoftware` command of the camera.
+    ///
+    /// The camera must be configured for software triggering, e.g. with
+    /// [BuiltinConfiguration::SoftwareTrigger](crate::BuiltinConfiguration).
+    pub fn execute_software_trigger(&self) -> PylonResult<()> {
+        ffi::instant_camera_execute_software_trigger(&self.inner).into_rust()
+    }
+
+    /// Async version of [InstantCamera::wait_for_frame_trigger_ready].
+    ///
+    /// The wait runs on tokio's blocking thread pool. The camera is returned
+    /// together with the result once the wait is over. If waiting fails, the
+    /// camera is dropped.
+    #[cfg(feature = "stream")]
+    pub async fn wait_for_frame_trigger_ready_async(
+        self,
+        timeout_ms: u32,
+        timeout_handling: TimeoutHandling,
+    ) -> PylonResult<(Self, bool)> {
+        self.run_blocking(move |camera| {
+            camera.wait_for_frame_trigger_ready(timeout_ms, timeout_handling)
+        })
+        .await
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{InstantCamera, NodeMap, PylonResult};
+
+/// The counters of the stream grabber, which receives the images on the
+/// host.
+///
+/// Which counters exist depends on the transport layer, e.g. packet and
+/// resend counters only exist for GigE cameras. Counters which the stream
+/// grabber does not have are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamStatistics {
+    /// The number of buffers processed, `Statistic_Total_Buffer_Count`.
+    pub total_buffer_count: Option<i64>,
+    /// The number of buffers which failed, `Statistic_Failed_Buffer_Count`.
+    pub failed_buffer_count: Option<i64>,
+    /// The number of frames lost because no buffer was queued,
+    /// `Statistic_Buffer_Underrun_Count`.
+    pub buffer_underrun_count: Option<i64>,
+    /// The number of packets received, `Statistic_Total_Packet_Count`.
+    pub total_packet_count: Option<i64>,
+    /// The number of packets which failed, `Statistic_Failed_Packet_Count`.
+    pub failed_packet_count: Option<i64>,
+    /// The number of resend requests sent to the camera,
+    /// `Statistic_Resend_Request_Count`.
+    pub resend_request_count: Option<i64>,
+    /// The number of packets requested to be resent,
+    /// `Statistic_Resend_Packet_Count`.
+    pub resend_packet_count: Option<i64>,
+    /// The number of frames the camera could not deliver,
+    /// `Statistic_Missed_Frame_Count`.
+    pub missed_frame_count: Option<i64>,
+    /// The number of times the stream had to be resynchronized,
+    /// `Statistic_Resynchronization_Count`.
+    pub resynchronization_count: Option<i64>,
+}
+
+/// The node names of the counters, in the order of `counters_mut`.
+const COUNTER_NAMES: [&str; 9] = [
+    "Statistic_Total_Buffer_Count",
+    "Statistic_Failed_Buffer_Count",
+    "Statistic_Buffer_Underrun_Count",
+    "Statistic_Total_Packet_Count",
+    "Statistic_Failed_Packet_Count",
+    "Statistic_Resend_Request_Count",
+    "Statistic_Resend_Packet_Count",
+    "Statistic_Missed_Frame_Count",
+    "Statistic_Resynchronization_Count",
+];
+
+impl StreamStatistics {
+    fn counters_mut(&mut self) -> [&mut Option<i64>; 9] {
+        [
+            &mut self.total_buffer_count,
+            &mut self.failed_buffer_count,
+            &mut self.buffer_underrun_count,
+            &mut self.total_packet_count,
+            &mut self.failed_packet_count,
+            &mut self.resend_request_count,
+            &mut self.resend_packet_count,
+            &mut self.missed_frame_count,
+            &mut self.resynchronization_count,
+        ]
+    }
+
+    /// Read the counters from a stream grabber node map.
+    pub fn read(node_map: &NodeMap) -> Self {
+        let mut statistics = Self::default();
+        for (counter, name) in IntoIterator::into_iter(statistics.counters_mut()).zip(COUNTER_NAMES)
+        {
+            if node_map.has_node(name) {
+                *counter = node_map
+                    .integer_node(name)
+                    .and_then(|node| node.value())
+                    .ok();
+            }
+        }
+        statistics
+    }
+
+    /// The counters since `baseline` was read.
+    fn since(mut self, mut baseline: Self) -> Self {
+        for (counter, base) in
+            IntoIterator::into_iter(self.counters_mut()).zip(baseline.counters_mut())
+        {
+            if let (Some(counter), Some(base)) = (counter, &base) {
+                // The stream grabber resets its counters when it is reopened.
+                if *counter >= *base {
+                    *counter -= *base;
+                }
+            }
+        }
+        self
+    }
+}
+
+impl InstantCamera<'_> {
+    /// Read the counters of the stream grabber, relative to the last call to
+    /// [InstantCamera::reset_stream_statistics].
+    pub fn stream_statistics(&self) -> PylonResult<StreamStatistics> {
+        let statistics = StreamStatistics::read(&self.stream_grabber_node_map()?);
+        Ok(
+            match self.state.stream_statistics_baseline.borrow().clone() {
+                Some(baseline) => statistics.since(baseline),
+                None => statistics,
+            },
+        )
+    }
+
+    /// Start counting from zero.
+    ///
+    /// The stream grabber counters cannot be reset, so this remembers their
+    /// current values and subtracts them from later snapshots.
+    pub fn reset_stream_statistics(&self) -> PylonResult<()> {
+        let baseline = StreamStatistics::read(&self.stream_grabber_node_map()?);
+        self.state
+            .stream_statistics_baseline
+            .replace(Some(baseline));
+        Ok(())
+    }
+}
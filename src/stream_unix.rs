@@ -1,45 +1,161 @@
-use crate::{GrabResult, InstantCamera, TimeoutHandling};
+use crate::{GrabResult, InstantCamera, PylonResult, TimeoutHandling};
+use std::os::unix::io::RawFd;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use tokio::io::unix::AsyncFd;
 use tokio_stream::Stream;
 
+/// The wait object fd of a camera, registered with the reactor of an async
+/// runtime.
+pub(crate) enum AsyncWaitFd {
+    Tokio(AsyncFd<RawFd>),
+    #[cfg(feature = "async-io")]
+    AsyncIo(async_io::Async<WaitObjectFd>),
+}
+
+/// The wait object fd, which is owned by pylon.
+#[cfg(feature = "async-io")]
+pub(crate) struct WaitObjectFd(RawFd);
+
+#[cfg(feature = "async-io")]
+impl std::os::unix::io::AsFd for WaitObjectFd {
+    fn as_fd(&self) -> std::os::unix::io::BorrowedFd<'_> {
+        // Safety: pylon keeps the fd open while the camera is grabbing and the
+        // fd is deregistered before grabbing stops.
+        unsafe { std::os::unix::io::BorrowedFd::borrow_raw(self.0) }
+    }
+}
+
 impl<'a> Stream for InstantCamera<'a> {
-    type Item = GrabResult;
+    type Item = PylonResult<GrabResult>;
 
-    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<GrabResult>> {
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_grab_result(cx)
+    }
+}
+
+impl InstantCamera<'_> {
+    /// Poll for the next grab result.
+    ///
+    /// Returns `None` once the camera stopped grabbing, e.g. because the
+    /// requested number of images has been grabbed, or after the error of
+    /// kind [ErrorKind::DeviceRemoved](crate::ErrorKind::DeviceRemoved). Once
+    /// the camera has been reconnected, results are delivered again.
+    pub(crate) fn poll_grab_result(
+        &self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<PylonResult<GrabResult>>> {
         if !self.is_grabbing() {
             return Poll::Ready(None);
         }
+        if self.is_camera_device_removed() {
+            // The wait object is destroyed with the device.
+            self.reset_async_wait();
+            return Poll::Ready(None);
+        }
+
+        let mut fd = self.state.fd.borrow_mut();
+        let fd = match &mut *fd {
+            Some(fd) => fd,
+            empty => match self.new_async_fd() {
+                Ok(fd) => empty.insert(fd),
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            },
+        };
+        self.poll_grab_result_with_fd(fd, cx)
+    }
+
+    /// Register the wait object fd with tokio if polled within a tokio
+    /// runtime and otherwise, with the `async-io` feature, with the reactor of
+    /// async-io, which works with any executor.
+    fn new_async_fd(&self) -> PylonResult<AsyncWaitFd> {
+        if tokio::runtime::Handle::try_current().is_ok() {
+            return Ok(AsyncWaitFd::Tokio(AsyncFd::new(
+                self.get_grab_result_fd()?,
+            )?));
+        }
+        #[cfg(feature = "async-io")]
+        {
+            // pylon's fd is not switched to non-blocking mode, it is never read.
+            Ok(AsyncWaitFd::AsyncIo(async_io::Async::new_nonblocking(
+                WaitObjectFd(self.get_grab_result_fd()?),
+            )?))
+        }
+        #[cfg(not(feature = "async-io"))]
+        Err(crate::PylonError::with_kind(
+            crate::ErrorKind::NoAsyncRuntime,
+            "The grab result stream must be polled within a tokio runtime or the `async-io` feature must be enabled",
+        ))
+    }
+
+    fn poll_grab_result_with_fd(
+        &self,
+        fd: &AsyncWaitFd,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<PylonResult<GrabResult>>> {
+        match fd {
+            AsyncWaitFd::Tokio(fd) => self.poll_grab_result_with_tokio(fd, cx),
+            #[cfg(feature = "async-io")]
+            AsyncWaitFd::AsyncIo(fd) => self.poll_grab_result_with_async_io(fd, cx),
+        }
+    }
 
-        let fd = self.fd.borrow_mut();
+    /// Retrieve a grab result without waiting. Returns `None` if there is no
+    /// result yet.
+    fn try_retrieve_result(&self) -> Option<Poll<Option<PylonResult<GrabResult>>>> {
+        let mut grab_result = match GrabResult::new() {
+            Ok(grab_result) => grab_result,
+            Err(e) => return Some(Poll::Ready(Some(Err(e)))),
+        };
+        match self.retrieve_result(0, &mut grab_result, TimeoutHandling::Return) {
+            Ok(true) => Some(Poll::Ready(Some(Ok(grab_result)))),
+            Ok(false) if !self.is_grabbing() => Some(Poll::Ready(None)),
+            Ok(false) => None,
+            Err(e) => Some(Poll::Ready(Some(Err(e)))),
+        }
+    }
 
+    #[cfg(feature = "async-io")]
+    fn poll_grab_result_with_async_io(
+        &self,
+        fd: &async_io::Async<WaitObjectFd>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<PylonResult<GrabResult>>> {
+        loop {
+            // async-io registers interest again on each call, so a result
+            // which arrived in between is not missed.
+            if let Some(poll) = self.try_retrieve_result() {
+                return poll;
+            }
+            match fd.poll_readable(cx) {
+                Poll::Ready(Ok(())) => continue,
+                Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e.into()))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn poll_grab_result_with_tokio(
+        &self,
+        fd: &AsyncFd<RawFd>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<PylonResult<GrabResult>>> {
         loop {
             // poll the wait object fd for readiness and continue if ready, if not ready
             // poll_read_ready calls the context's waker if we can make progress
-            match fd.as_ref()
-                .expect("No wait object fd present (during start_grabbing, no tokio handler was available).")
-                .poll_read_ready(cx)
-            {
+            match fd.poll_read_ready(cx) {
                 Poll::Ready(Ok(mut g)) => {
-                        let mut grab_result = GrabResult::new().expect("Creating a GrabResult should not fail");
-                        // we know we're ready, so we don't have to wait at all
-                        match self.retrieve_result(0, &mut grab_result, TimeoutHandling::Return) {
-                            Ok(true) => return Poll::Ready(Some(grab_result)),
-                            Ok(false) => {
-                                g.clear_ready();
-                                continue;
-                                },
-                            Err(_) => {
-                                return Poll::Ready(None)
+                    // we know we're ready, so we don't have to wait at all
+                    match self.try_retrieve_result() {
+                        Some(poll) => return poll,
+                        None => {
+                            g.clear_ready();
+                            continue;
                         }
                     }
                 }
-                Poll::Ready(Err(e)) => {
-                    panic!("Camera FD failed with error: {}", e);
-                }
-                Poll::Pending => {
-                    return Poll::Pending;
-                }
+                Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e.into()))),
+                Poll::Pending => return Poll::Pending,
             }
         }
     }
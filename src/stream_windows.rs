@@ -3,36 +3,62 @@ use std::{
     task::{Context, Poll},
 };
 
-use crate::{GrabResult, InstantCamera, TimeoutHandling};
+use crate::{GrabResult, InstantCamera, PylonResult, TimeoutHandling};
 use tokio_stream::Stream;
 
 impl Stream for InstantCamera<'_> {
-    type Item = GrabResult;
+    type Item = PylonResult<GrabResult>;
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<GrabResult>> {
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_grab_result(cx)
+    }
+}
+
+impl InstantCamera<'_> {
+    /// Poll for the next grab result.
+    ///
+    /// Returns `None` once the camera stopped grabbing, e.g. because the
+    /// requested number of images has been grabbed, or after the error of
+    /// kind [ErrorKind::DeviceRemoved](crate::ErrorKind::DeviceRemoved). Once
+    /// the camera has been reconnected, results are delivered again.
+    pub(crate) fn poll_grab_result(
+        &self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<PylonResult<GrabResult>>> {
         if !self.is_grabbing() {
             return Poll::Ready(None);
         }
+        if self.is_camera_device_removed() {
+            // The wait object is destroyed with the device.
+            self.reset_async_wait();
+            return Poll::Ready(None);
+        }
 
-        let waker = cx.waker().clone();
-        let wait_object = self
-            .get_grab_result_wait_object()
-            .expect("Getting a camera wait-object should not fail");
-        if self.wait_thread.borrow().is_none() {
-            self.wait_thread = Some(std::thread::spawn(move || {
-                while let Ok(true) = wait_object.wait(u64::MAX) {
-                    waker.wake_by_ref();
-                }
-            }))
-            .into();
+        if self.state.wait_thread.borrow().is_none() {
+            let waker = cx.waker().clone();
+            let wait_object = match self.get_grab_result_wait_object() {
+                Ok(wait_object) => wait_object,
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            };
+            self.state
+                .wait_thread
+                .replace(Some(std::thread::spawn(move || {
+                    while let Ok(true) = wait_object.wait(u64::MAX) {
+                        waker.wake_by_ref();
+                    }
+                })));
         }
 
-        let mut grab_result = GrabResult::new().expect("Creating a GrabResult should not fail");
+        let mut grab_result = match GrabResult::new() {
+            Ok(grab_result) => grab_result,
+            Err(e) => return Poll::Ready(Some(Err(e))),
+        };
         // we know we're ready, so we don't have to wait at all
         match self.retrieve_result(0, &mut grab_result, TimeoutHandling::Return) {
-            Ok(true) => Poll::Ready(Some(grab_result)),
+            Ok(true) => Poll::Ready(Some(Ok(grab_result))),
+            Ok(false) if !self.is_grabbing() => Poll::Ready(None),
             Ok(false) => Poll::Pending,
-            Err(_) => Poll::Ready(None),
+            Err(e) => Poll::Ready(Some(Err(e))),
         }
     }
 }
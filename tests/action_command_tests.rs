@@ -0,0 +1,26 @@
+use pylon_cxx::ActionCommand;
+
+#[test]
+fn action_command_builder_works() {
+    let command = ActionCommand::new(0x4711, 0x1);
+    assert_eq!(
+        command,
+        ActionCommand {
+            device_key: 0x4711,
+            group_key: 0x1,
+            group_mask: 0xffff_ffff,
+        }
+    );
+    assert_eq!(command.group_mask(0x2).group_mask, 0x2);
+}
+
+#[test]
+fn action_command_arguments_are_validated() {
+    let command = ActionCommand::new(0x4711, 0x1);
+    assert!(command.validate("255.255.255.255").is_ok());
+    assert!(command.validate("192.168.1.255").is_ok());
+    assert!(command.validate("").is_err());
+    assert!(command.validate("not an address").is_err());
+    assert!(command.validate("::1").is_err());
+    assert!(command.group_mask(0).validate("255.255.255.255").is_err());
+}
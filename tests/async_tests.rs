@@ -1,17 +1,22 @@
 #![cfg(feature = "stream")]
-use pylon_cxx::{GrabOptions, Pylon, PylonError, PylonResult, TlFactory};
+mod common;
+
+use pylon_cxx::{
+    BuiltinConfiguration, GrabOptions, PylonError, PylonResult, RegistrationMode, TimeoutHandling,
+    TlFactory,
+};
 use tokio_stream::{StreamExt, StreamMap};
 
 #[tokio::test]
 async fn streaming_works() -> PylonResult<()> {
     let mut images = 10;
-    let pylon = Pylon::new();
-    let mut cam = TlFactory::instance(&pylon).create_first_device()?;
+    let pylon = common::pylon();
+    let mut cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
     cam.open()?;
     cam.start_grabbing(&GrabOptions::default().count(images))?;
     while let Some(res) = cam.next().await {
         images -= 1;
-        assert!(res.grab_succeeded()?);
+        assert!(res?.grab_succeeded()?);
     }
     assert_eq!(images, 0);
     Ok(())
@@ -19,10 +24,10 @@ async fn streaming_works() -> PylonResult<()> {
 
 #[tokio::test]
 async fn streaming_all_cams_works() -> PylonResult<()> {
-    let pylon = Pylon::new();
+    let pylon = common::pylon();
     let mut streams = StreamMap::new();
     TlFactory::instance(&pylon)
-        .enumerate_devices()?
+        .enumerate_emulated_devices()?
         .iter()
         .enumerate()
         .try_for_each(|(n, info)| {
@@ -35,27 +40,144 @@ async fn streaming_all_cams_works() -> PylonResult<()> {
 
     for _ in 0..50 {
         let (id, res) = streams.next().await.unwrap();
-        assert!(res.grab_succeeded()?);
+        assert!(res?.grab_succeeded()?);
         println!("Cam: {:?}", id);
     }
 
     Ok(())
 }
 
+#[test]
+fn restarting_in_another_runtime_works() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let mut cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    // Grabbing ends after `count` results without `stop_grabbing`, so the
+    // next `start_grabbing` must register the wait object with the new
+    // runtime.
+    for _ in 0..2 {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        runtime.block_on(async {
+            let mut images = 3;
+            cam.start_grabbing(&GrabOptions::default().count(images))?;
+            while let Some(res) = cam.next().await {
+                images -= 1;
+                assert!(res?.grab_succeeded()?);
+            }
+            assert_eq!(images, 0);
+            Ok::<_, PylonError>(())
+        })?;
+    }
+    Ok(())
+}
+
 #[tokio::test]
 async fn start_stop_loop_works() -> PylonResult<()> {
-    let pylon = Pylon::new();
-    let mut cam = TlFactory::instance(&pylon).create_first_device()?;
+    let pylon = common::pylon();
+    let mut cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
     cam.open()?;
     for _ in 0..5 {
         let mut images = 10;
         cam.start_grabbing(&GrabOptions::default().count(images))?;
         while let Some(res) = cam.next().await {
             images -= 1;
-            assert!(res.grab_succeeded()?);
+            assert!(res?.grab_succeeded()?);
         }
         assert_eq!(images, 0);
         cam.stop_grabbing()?;
     }
     Ok(())
 }
+
+#[tokio::test]
+async fn grab_stream_leaves_camera_usable() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    let width = cam.node_map()?.integer_node("Width")?.value()?;
+    {
+        let mut images = 10;
+        let mut stream = cam.grab_stream(&GrabOptions::default())?;
+        while let Some(res) = stream.next().await {
+            assert_eq!(i64::from(res?.width()?), width);
+            assert_eq!(cam.node_map()?.integer_node("Width")?.value()?, width);
+            images -= 1;
+            if images == 0 {
+                break;
+            }
+        }
+    }
+    assert!(!cam.is_grabbing());
+    Ok(())
+}
+
+#[cfg(all(feature = "async-io", not(target_os = "windows")))]
+#[test]
+fn streaming_without_tokio_works() -> PylonResult<()> {
+    let mut images = 10;
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    let mut stream = cam.grab_stream(&GrabOptions::default().count(images))?;
+    async_io::block_on(async {
+        while let Some(res) = stream.next().await {
+            images -= 1;
+            assert!(res?.grab_succeeded()?);
+        }
+        Ok::<_, PylonError>(())
+    })?;
+    assert_eq!(images, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn async_open_works() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let tl_factory = TlFactory::instance(&pylon);
+    let emulated = tl_factory.enumerate_emulated_devices()?;
+    let devices = tl_factory.enumerate_devices_async().await?;
+    assert!(emulated.iter().all(|device| devices.contains(device)));
+
+    let cam = tl_factory.create_device_async(&emulated[0]).await?;
+    let cam = cam.open_async().await?;
+    assert!(cam.is_open()?);
+    let path = std::env::temp_dir().join(format!("pylon-cxx-{}.pfs", std::process::id()));
+    cam.node_map()?.save(&path)?;
+    let cam = cam.load_node_map_async(&path, true).await;
+    std::fs::remove_file(&path)?;
+    let cam = cam?.close_async().await?;
+    assert!(!cam.is_open()?);
+    Ok(())
+}
+
+#[cfg(all(feature = "async-io", not(target_os = "windows")))]
+#[test]
+fn async_open_without_tokio_works() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    let cam = async_io::block_on(async { cam.open_async().await?.close_async().await })?;
+    assert!(!cam.is_open()?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn async_frame_trigger_wait_works() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.register_builtin_configuration(
+        RegistrationMode::ReplaceAll,
+        BuiltinConfiguration::SoftwareTrigger,
+    )?;
+    cam.open()?;
+    cam.start_grabbing(&GrabOptions::default())?;
+    if cam.can_wait_for_frame_trigger_ready()? {
+        let (cam, ready) = cam
+            .wait_for_frame_trigger_ready_async(1000, TimeoutHandling::Return)
+            .await?;
+        assert!(ready);
+        cam.stop_grabbing()?;
+    }
+    Ok(())
+}
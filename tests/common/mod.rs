@@ -0,0 +1,20 @@
+//! Helpers shared by the integration tests.
+
+use std::sync::Once;
+
+use pylon_cxx::Pylon;
+
+/// The number of emulated cameras the tests use.
+pub const EMULATED_DEVICES: u32 = 2;
+
+/// Initialize the pylon runtime with the emulated cameras the tests use.
+pub fn pylon() -> Pylon {
+    static EMULATION: Once = Once::new();
+    EMULATION.call_once(|| {
+        // SAFETY: The other tests block on `EMULATION` until the environment
+        // has been set up, and nothing else in the test process reads it.
+        unsafe { Pylon::with_emulated_devices(EMULATED_DEVICES) }
+            .expect("could not set up the emulated cameras");
+    });
+    Pylon::new()
+}
@@ -0,0 +1,549 @@
+mod common;
+
+use pylon_cxx::{
+    AccessMode, BufferAllocator, BuiltinConfiguration, CameraGroup, ClockSync, Configuration,
+    ConfigurationEventHandler, DeviceAccessibility, EmulatedImageSource, ErrorKind, Frame,
+    GrabLoop, GrabOptions, GrabResult, GrabWorker, InstantCameraRef, MatchBy, OverflowPolicy,
+    Pylon, PylonResult, ReconnectPolicy, RecvTimeoutError, RegistrationMode, TestImage,
+    TimeoutHandling, TlFactory,
+};
+
+#[test]
+fn emulated_devices_are_enumerated() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let devices = TlFactory::instance(&pylon).enumerate_emulated_devices()?;
+    assert_eq!(devices.len(), common::EMULATED_DEVICES as usize);
+    for device in devices.iter() {
+        assert_eq!(device.device_class()?, "BaslerCamEmu");
+    }
+    Ok(())
+}
+
+#[test]
+fn emulated_device_count_cannot_change_while_initialized() {
+    let _pylon = common::pylon();
+    // The runtime is initialized, so the environment is not modified.
+    let count = common::EMULATED_DEVICES;
+    assert!(unsafe { Pylon::with_emulated_devices(count + 1) }.is_err());
+    assert!(unsafe { Pylon::with_emulated_devices(count) }.is_ok());
+}
+
+#[test]
+fn grabbing_test_image_works() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    cam.set_emulated_image_source(&EmulatedImageSource::TestImage(TestImage::Testimage1))?;
+    let width = cam.node_map()?.integer_node("Width")?.value()?;
+    let height = cam.node_map()?.integer_node("Height")?.value()?;
+
+    let mut images = 10;
+    cam.start_grabbing(&GrabOptions::default().count(images))?;
+    for grab_result in cam.frames(5000) {
+        let grab_result = grab_result?;
+        images -= 1;
+        assert!(grab_result.grab_succeeded()?);
+        assert_eq!(i64::from(grab_result.width()?), width);
+        assert_eq!(i64::from(grab_result.height()?), height);
+    }
+    assert_eq!(images, 0);
+    Ok(())
+}
+
+struct SetWidth(i64);
+
+impl Configuration for SetWidth {
+    fn configure(&mut self, camera: &InstantCameraRef<'_>) -> PylonResult<()> {
+        camera.node_map()?.integer_node("Width")?.set_value(self.0)
+    }
+}
+
+#[test]
+fn configuration_is_applied_on_open() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.register_configuration(RegistrationMode::Append, SetWidth(320))?;
+    cam.open()?;
+    assert_eq!(cam.node_map()?.integer_node("Width")?.value()?, 320);
+    cam.node_map()?.integer_node("Width")?.set_value(640)?;
+    cam.close()?;
+
+    cam.open()?;
+    assert_eq!(cam.node_map()?.integer_node("Width")?.value()?, 320);
+    Ok(())
+}
+
+#[test]
+fn software_trigger_works() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.register_builtin_configuration(
+        RegistrationMode::ReplaceAll,
+        BuiltinConfiguration::SoftwareTrigger,
+    )?;
+    cam.open()?;
+    cam.start_grabbing(&GrabOptions::default())?;
+    let mut grab_result = GrabResult::new()?;
+    for _ in 0..3 {
+        if cam.can_wait_for_frame_trigger_ready()? {
+            cam.wait_for_frame_trigger_ready(1000, TimeoutHandling::ThrowException)?;
+        }
+        cam.execute_software_trigger()?;
+        cam.retrieve_result(5000, &mut grab_result, TimeoutHandling::ThrowException)?;
+        assert!(grab_result.grab_succeeded()?);
+    }
+    cam.stop_grabbing()
+}
+
+#[test]
+fn grab_one_works() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    let grab_result = cam.grab_one(5000)?;
+    assert!(grab_result.grab_succeeded()?);
+    assert!(!cam.is_grabbing());
+    Ok(())
+}
+
+#[test]
+fn out_of_range_grab_options_are_rejected() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    assert!(cam
+        .start_grabbing(&GrabOptions::default().max_num_buffer(0))
+        .is_err());
+    assert!(!cam.is_grabbing());
+
+    cam.start_grabbing(&GrabOptions::default().max_num_buffer(5).count(1))?;
+    assert_eq!(
+        cam.instant_camera_node_map()?
+            .integer_node("MaxNumBuffer")?
+            .value()?,
+        5
+    );
+    Ok(())
+}
+
+#[test]
+fn frames_can_be_shared_between_threads() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    let frame = std::sync::Arc::new(Frame::new(cam.grab_one(5000)?)?);
+    let expected = frame.data().to_vec();
+    let other = frame.clone();
+    let copied = std::thread::spawn(move || other.data().to_vec())
+        .join()
+        .unwrap();
+    assert_eq!(copied, expected);
+
+    let grab_result = frame.grab_result().clone();
+    assert_eq!(grab_result.buffer()?, &expected[..]);
+    Ok(())
+}
+
+#[test]
+fn frames_reuse_grab_result() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    let mut images = 10;
+    cam.start_grabbing(&GrabOptions::default().count(images))?;
+    let mut frames = cam.frames(5000);
+    while let Some(grab_result) = frames.next_ref() {
+        assert!(grab_result?.grab_succeeded()?);
+        images -= 1;
+    }
+    assert_eq!(images, 0);
+    Ok(())
+}
+
+#[test]
+fn grab_worker_delivers_and_drops_frames() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    let worker = GrabWorker::spawn(
+        cam,
+        &GrabOptions::default().count(5),
+        2,
+        OverflowPolicy::Block,
+    )?;
+    let receiver = worker.receiver();
+    let received = std::thread::spawn(move || {
+        let mut images = 0;
+        while let Some(grab_result) = receiver.recv() {
+            assert!(grab_result.unwrap().grab_succeeded().unwrap());
+            images += 1;
+        }
+        images
+    })
+    .join()
+    .unwrap();
+    assert_eq!(received, 5);
+    assert_eq!(worker.dropped_frames(), 0);
+    let cam = worker.stop()?;
+    assert!(!cam.is_grabbing());
+
+    let worker = GrabWorker::spawn(
+        cam,
+        &GrabOptions::default().count(5),
+        1,
+        OverflowPolicy::DropNewest,
+    )?;
+    while !worker.is_finished() {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(worker.queue_depth(), 1);
+    assert_eq!(worker.dropped_frames(), 4);
+    Ok(())
+}
+
+#[test]
+fn grab_statistics_are_recorded() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    assert!(cam.grab_statistics().is_none());
+    cam.enable_grab_statistics();
+    cam.start_grabbing(&GrabOptions::default().count(10))?;
+    for grab_result in cam.frames(5000) {
+        grab_result?;
+    }
+    let statistics = cam.grab_statistics().unwrap();
+    assert_eq!(statistics.frames(), 10);
+    assert_eq!(statistics.failed(), 0);
+    assert_eq!(statistics.missing_block_ids(), 0);
+    assert!(statistics.bytes() > 0);
+    let intervals: u64 = statistics
+        .interval_histogram()
+        .iter()
+        .map(|(_, count)| count)
+        .sum();
+    assert_eq!(intervals, 9);
+
+    cam.grab_one(5000)?;
+    assert_eq!(cam.grab_statistics().unwrap().frames(), 11);
+    Ok(())
+}
+
+#[test]
+fn stream_statistics_can_be_reset() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    cam.start_grabbing(&GrabOptions::default().count(5))?;
+    for grab_result in cam.frames(5000) {
+        grab_result?;
+    }
+    let statistics = cam.stream_statistics()?;
+    cam.reset_stream_statistics()?;
+    let reset = cam.stream_statistics()?;
+    if statistics.total_buffer_count.is_some() {
+        assert_eq!(reset.total_buffer_count, Some(0));
+    }
+    assert_eq!(
+        reset.total_packet_count.is_some(),
+        statistics.total_packet_count.is_some()
+    );
+    Ok(())
+}
+
+#[test]
+fn clock_sync_requires_timestamp_latch() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    // Emulated cameras cannot latch their timestamp.
+    let error = ClockSync::new(&cam).err().unwrap();
+    assert_eq!(error.kind(), ErrorKind::Unsupported);
+    Ok(())
+}
+
+#[test]
+fn ptp_requires_camera_support() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    // Emulated cameras do not support PTP.
+    assert_eq!(
+        cam.enable_ptp(true).unwrap_err().kind(),
+        ErrorKind::Unsupported
+    );
+    assert_eq!(cam.ptp_status().unwrap_err().kind(), ErrorKind::Unsupported);
+    Ok(())
+}
+
+#[test]
+fn camera_group_matches_frames() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let tl_factory = TlFactory::instance(&pylon);
+    let cameras = tl_factory
+        .enumerate_emulated_devices()?
+        .iter()
+        .map(|device| tl_factory.create_device(device))
+        .collect::<PylonResult<Vec<_>>>()?;
+    let mut group = CameraGroup::start(
+        cameras,
+        &GrabOptions::default().count(5),
+        8,
+        OverflowPolicy::Block,
+        MatchBy::BlockId,
+    )?;
+    let mut sets = 0;
+    loop {
+        let set = match group.recv_timeout(std::time::Duration::from_secs(5)) {
+            Ok(set) => set?,
+            Err(RecvTimeoutError::Finished) => break,
+            Err(RecvTimeoutError::Timeout) => panic!("no frame set within 5 s"),
+        };
+        assert!(set.is_complete());
+        assert_eq!(set.grab_results.len(), 2);
+        sets += 1;
+    }
+    assert_eq!(sets, 5);
+    assert!(group.recv().is_none());
+    assert_eq!(group.incomplete_sets(), 0);
+    assert_eq!(group.stop()?.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn emulated_device_is_accessible() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let tl_factory = TlFactory::instance(&pylon);
+    let device = tl_factory.enumerate_emulated_devices()?.remove(0);
+    assert!(
+        tl_factory.is_device_accessible_with_access_mode(&device, &[AccessMode::Control])?
+            == DeviceAccessibility::Ok
+    );
+    assert!(tl_factory.is_device_accessible(&device)? == DeviceAccessibility::Ok);
+    Ok(())
+}
+
+#[test]
+fn monitor_accessibility_is_unsupported() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let tl_factory = TlFactory::instance(&pylon);
+    let device = tl_factory.enumerate_emulated_devices()?.remove(0);
+    let error = tl_factory
+        .is_device_accessible_with_access_mode(&device, &[AccessMode::Monitor])
+        .err()
+        .unwrap();
+    assert_eq!(error.kind(), ErrorKind::Unsupported);
+    Ok(())
+}
+
+#[test]
+fn opening_with_access_mode_applies_configurations() -> PylonResult<()> {
+    struct RecordOpened(std::sync::Arc<std::sync::Mutex<usize>>);
+
+    impl ConfigurationEventHandler for RecordOpened {
+        fn on_opened(&mut self, _camera: &InstantCameraRef<'_>) {
+            *self.0.lock().unwrap() += 1;
+        }
+    }
+
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.register_configuration(RegistrationMode::Append, SetWidth(320))?;
+    let opened = std::sync::Arc::new(std::sync::Mutex::new(0));
+    let _registration = cam.register_configuration_event_handler(
+        RegistrationMode::Append,
+        RecordOpened(opened.clone()),
+    )?;
+    cam.open_with_access_mode(&[AccessMode::Control, AccessMode::Stream])?;
+    assert_eq!(*opened.lock().unwrap(), 1);
+    assert_eq!(cam.node_map()?.integer_node("Width")?.value()?, 320);
+    cam.close()?;
+
+    cam.open_with_access_mode(&[AccessMode::Control, AccessMode::Stream, AccessMode::Event])?;
+    assert_eq!(*opened.lock().unwrap(), 2);
+    assert_eq!(cam.node_map()?.integer_node("Width")?.value()?, 320);
+    Ok(())
+}
+
+#[test]
+fn invalid_access_modes_are_rejected() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    assert!(cam.open_with_access_mode(&[]).is_err());
+    assert!(cam
+        .open_with_access_mode(&[AccessMode::Monitor, AccessMode::Control])
+        .is_err());
+    assert!(!cam.is_open()?);
+    Ok(())
+}
+
+#[test]
+fn monitor_mode_cannot_grab() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open_with_access_mode(&[AccessMode::Monitor])?;
+    assert!(cam.grab_one(1000).is_err());
+    Ok(())
+}
+
+#[test]
+fn reconnect_requires_policy() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    assert!(cam.reconnect().is_err());
+    assert!(cam.is_open()?);
+    Ok(())
+}
+
+#[test]
+fn reconnect_restores_node_values() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.set_reconnect_policy(Some(
+        ReconnectPolicy::default().timeout(std::time::Duration::from_secs(5)),
+    ))?;
+    cam.open()?;
+    cam.node_map()?.integer_node("Width")?.set_value(320)?;
+    cam.update_reconnect_snapshot()?;
+    cam.node_map()?.integer_node("Height")?.set_value(240)?;
+    cam.start_grabbing(&GrabOptions::default())?;
+
+    // Emulated cameras cannot be removed, but reconnecting to one which is
+    // still present goes through the same steps.
+    cam.reconnect()?;
+    assert!(cam.is_open()?);
+    assert!(cam.is_grabbing());
+    assert_eq!(cam.node_map()?.integer_node("Width")?.value()?, 320);
+    // Changed after the snapshot was taken, so not restored.
+    assert_ne!(cam.node_map()?.integer_node("Height")?.value()?, 240);
+    cam.stop_grabbing()
+}
+
+#[test]
+fn image_event_handler_is_called_for_each_result() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    let (tx, rx) = std::sync::mpsc::channel();
+    let _registration =
+        cam.register_image_event_handler(RegistrationMode::Append, move |_camera, grab_result| {
+            let _ = tx.send(grab_result.grab_succeeded());
+        })?;
+
+    let images = 5;
+    cam.start_grabbing(
+        &GrabOptions::default()
+            .count(images)
+            .grab_loop(GrabLoop::ProvidedByInstantCamera),
+    )?;
+    for _ in 0..images {
+        let grab_succeeded = rx
+            .recv_timeout(std::time::Duration::from_secs(5))
+            .expect("image event handler was not called");
+        assert!(grab_succeeded?);
+    }
+    assert!(rx
+        .recv_timeout(std::time::Duration::from_millis(200))
+        .is_err());
+    cam.stop_grabbing()
+}
+
+#[test]
+fn camera_event_handler_can_be_deregistered() -> PylonResult<()> {
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    let registration = cam.on_camera_event("ExposureEnd", |_camera, _event| {})?;
+    drop(registration);
+
+    // The handler can be registered again and the camera keeps grabbing.
+    let _registration = cam.on_camera_event("ExposureEnd", |_camera, _event| {})?;
+    assert!(cam.grab_one(5000)?.grab_succeeded()?);
+    Ok(())
+}
+
+#[test]
+fn configuration_event_handler_is_called_on_open_and_close() -> PylonResult<()> {
+    struct RecordEvents(std::sync::Arc<std::sync::Mutex<Vec<&'static str>>>);
+
+    impl ConfigurationEventHandler for RecordEvents {
+        fn on_opened(&mut self, _camera: &InstantCameraRef<'_>) {
+            self.0.lock().unwrap().push("opened");
+        }
+
+        fn on_closed(&mut self, _camera: &InstantCameraRef<'_>) {
+            self.0.lock().unwrap().push("closed");
+        }
+    }
+
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    let events = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+    let _registration = cam.register_configuration_event_handler(
+        RegistrationMode::Append,
+        RecordEvents(events.clone()),
+    )?;
+    cam.open()?;
+    assert_eq!(*events.lock().unwrap(), ["opened"]);
+    cam.close()?;
+    assert_eq!(*events.lock().unwrap(), ["opened", "closed"]);
+    Ok(())
+}
+
+#[test]
+fn buffer_context_is_returned_with_grab_results() -> PylonResult<()> {
+    use std::alloc::{alloc, dealloc, Layout};
+    use std::collections::HashMap;
+    use std::ptr::NonNull;
+    use std::sync::{Arc, Mutex};
+
+    /// The address of each allocated buffer by its context.
+    type Buffers = Arc<Mutex<HashMap<isize, usize>>>;
+
+    struct CountingAllocator {
+        buffers: Buffers,
+        layouts: HashMap<isize, Layout>,
+        next_context: isize,
+    }
+
+    unsafe impl BufferAllocator for CountingAllocator {
+        fn allocate(&mut self, size: usize) -> PylonResult<(NonNull<u8>, isize)> {
+            let layout = Layout::from_size_align(size.max(1), 8)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+            let buffer = NonNull::new(unsafe { alloc(layout) })
+                .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::OutOfMemory))?;
+            let context = self.next_context;
+            self.next_context += 1;
+            self.layouts.insert(context, layout);
+            self.buffers
+                .lock()
+                .unwrap()
+                .insert(context, buffer.as_ptr() as usize);
+            Ok((buffer, context))
+        }
+
+        unsafe fn free(&mut self, buffer: NonNull<u8>, context: isize) {
+            if let Some(layout) = self.layouts.remove(&context) {
+                self.buffers.lock().unwrap().remove(&context);
+                dealloc(buffer.as_ptr(), layout);
+            }
+        }
+    }
+
+    let buffers = Buffers::default();
+    let pylon = common::pylon();
+    let cam = TlFactory::instance(&pylon).create_first_emulated_device()?;
+    cam.open()?;
+    cam.set_buffer_allocator(CountingAllocator {
+        buffers: buffers.clone(),
+        layouts: HashMap::new(),
+        next_context: 1,
+    })?;
+    cam.start_grabbing(&GrabOptions::default().count(5))?;
+    for grab_result in cam.frames(5000) {
+        let grab_result = grab_result?;
+        let context = grab_result.buffer_context()?;
+        let address = buffers.lock().unwrap().get(&context).copied();
+        assert_eq!(address, Some(grab_result.buffer()?.as_ptr() as usize));
+    }
+    drop(cam);
+    assert!(buffers.lock().unwrap().is_empty());
+    Ok(())
+}